@@ -0,0 +1,83 @@
+use companies_house_api::{
+    streaming::{
+        operation::filings::StreamFilings, CompaniesHouseStreamingNextError, LineFramer,
+        ReplayStream, DEFAULT_MAX_LINE_LENGTH,
+    },
+    test_util::fixtures::FILINGS_STREAM,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::io::Cursor;
+
+/// Repeats the recorded filings stream, with a heartbeat newline after each
+/// pass, so chunks split real items at varying offsets.
+fn recording(passes: usize) -> Vec<u8> {
+    let mut body = Vec::new();
+    for _ in 0..passes {
+        body.extend_from_slice(FILINGS_STREAM.as_bytes());
+        body.push(b'\n');
+    }
+    body
+}
+
+fn frame_all(body: &[u8], chunk_size: usize) -> usize {
+    let mut framer = LineFramer::new(DEFAULT_MAX_LINE_LENGTH);
+    let mut lines = 0;
+    for chunk in body.chunks(chunk_size) {
+        framer.push(chunk);
+        while let Some(line) = framer.next_line().unwrap() {
+            lines += line.len();
+        }
+    }
+    lines
+}
+
+fn replay_all(runtime: &tokio::runtime::Runtime, body: &[u8]) -> usize {
+    let mut replay = ReplayStream::new(StreamFilings, Cursor::new(body.to_vec()));
+    runtime.block_on(async {
+        let mut items = 0;
+        loop {
+            match replay.next().await {
+                Ok(_) => items += 1,
+                Err(CompaniesHouseStreamingNextError::StreamComplete) => return items,
+                Err(error) => panic!("{error}"),
+            }
+        }
+    })
+}
+
+fn bench_filings(c: &mut Criterion) {
+    let body = recording(1_000);
+    let mut group = c.benchmark_group("filings");
+    group.throughput(Throughput::Bytes(body.len() as u64));
+    for chunk_size in [64, 1024, 16 * 1024] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(chunk_size),
+            &chunk_size,
+            |b, &chunk_size| b.iter(|| frame_all(&body, chunk_size)),
+        );
+    }
+    group.finish();
+}
+
+fn bench_replay(c: &mut Criterion) {
+    let body = recording(1_000);
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let mut group = c.benchmark_group("replay");
+    group.throughput(Throughput::Bytes(body.len() as u64));
+    group.bench_function("filings", |b| b.iter(|| replay_all(&runtime, &body)));
+    group.finish();
+}
+
+fn bench_large_item(c: &mut Criterion) {
+    let mut body = vec![b' '; 4 * 1024 * 1024];
+    body.push(b'\n');
+    let mut group = c.benchmark_group("large_item");
+    group.throughput(Throughput::Bytes(body.len() as u64));
+    group.bench_function("64", |b| b.iter(|| frame_all(&body, 64)));
+    group.finish();
+}
+
+criterion_group!(benches, bench_filings, bench_replay, bench_large_item);
+criterion_main!(benches);
@@ -0,0 +1,46 @@
+use companies_house_api::{
+    streaming::{
+        operation::{
+            companies::StreamCompanies,
+            filings::{FilingHistory, StreamFilings},
+            StreamItem,
+        },
+        CompaniesHouseStreamingClient, StreamMultiplexer,
+    },
+    types::CompanyProfile,
+};
+
+#[derive(Debug)]
+enum Item {
+    Company(Box<StreamItem<CompanyProfile>>),
+    Filing(Box<StreamItem<FilingHistory>>),
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env_logger::init();
+
+    dotenvy::from_filename(".env.local")?;
+
+    let api_key = std::env::var("COMPANIES_HOUSE_STREAMING_API_KEY")?;
+    let client = CompaniesHouseStreamingClient::new(&api_key);
+
+    let mut multiplexer = StreamMultiplexer::new(client);
+    multiplexer.subscribe(StreamCompanies, None, |item| Item::Company(Box::new(item)))?;
+    multiplexer.subscribe(StreamFilings, None, |item| Item::Filing(Box::new(item)))?;
+
+    while let Some(item) = multiplexer.next().await {
+        match item.result {
+            Ok(Item::Company(company)) => {
+                log::info!(timepoint = company.event.timepoint; "Company received: {}", company.data.company_name)
+            }
+            Ok(Item::Filing(filing)) => {
+                log::info!(timepoint = filing.event.timepoint; "Filing received: {}", filing.data.description)
+            }
+            Err(err) => log::error!(endpoint = item.endpoint; "Stream error: {err}"),
+        }
+        log::debug!("Timepoints: {:?}", multiplexer.timepoints());
+    }
+
+    Ok(())
+}
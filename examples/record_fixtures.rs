@@ -0,0 +1,270 @@
+//! Records live Public Data API responses into `src/test_util/fixtures/`.
+//!
+//! ```sh
+//! cargo run --example record_fixtures -- <company_number> <search term>
+//! ```
+//!
+//! The company number is replaced with `fixtures::COMPANY_NUMBER` so the mock
+//! server keeps serving the recordings, and the names, addresses and dates of
+//! birth of people are redacted. Responses which do not parse as the
+//! operation's data type are still recorded, and reported so the type can be
+//! fixed. Review the diff before committing.
+
+use companies_house_api::{
+    operation::{
+        get_company_insolvency::GetCompanyInsolvency, get_company_profile::GetCompanyProfile,
+        get_company_registered_office_address::GetCompanyRegisteredOfficeAddress,
+        get_company_registers::GetCompanyRegisters, list_company_charges::ListCompanyCharges,
+        list_company_filing_history::ListCompanyFilingHistory,
+        list_company_officers::ListCompanyOfficers,
+        list_company_persons_with_significant_control::ListCompanyPersonsWithSignificantControl,
+        search_companies::SearchCompanies,
+        search_disqualified_officers::SearchDisqualifiedOfficers, search_officers::SearchOfficers,
+        CompaniesHousePublicDataOperation, CompaniesHousePublicDataOperationError,
+    },
+    test_util::fixtures::COMPANY_NUMBER,
+    transport::OperationRequest,
+    types::CompanyNumber,
+    CompaniesHousePublicDataClient,
+};
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+/// Members holding details of a person, which are redacted wherever they occur
+/// in a response about people. Dates of birth are replaced separately, see
+/// [`redact_date_of_birth`].
+const PERSONAL_MEMBERS: &[&str] = &[
+    "address",
+    "address_snippet",
+    "forename",
+    "name",
+    "name_elements",
+    "other_forenames",
+    "surname",
+    "title",
+];
+
+/// Sends an operation, keeping the response body as JSON so it is recorded
+/// exactly as sent.
+struct Raw<O>(O);
+
+impl<O: CompaniesHousePublicDataOperation> CompaniesHousePublicDataOperation for Raw<O> {
+    type StatusError = O::StatusError;
+    type Data = Value;
+
+    const PATH_TEMPLATE: &'static str = O::PATH_TEMPLATE;
+
+    fn build_request(&self) -> OperationRequest {
+        self.0.build_request()
+    }
+
+    fn handle_status(&self, response: StatusCode) -> Result<(), Self::StatusError> {
+        self.0.handle_status(response)
+    }
+}
+
+struct Recorder {
+    client: CompaniesHousePublicDataClient,
+    company_number: CompanyNumber,
+    fixtures: PathBuf,
+}
+
+impl Recorder {
+    /// Record the response to `operation` in `file`, redacting the details of
+    /// people if it describes them.
+    async fn record<O>(&self, file: &str, operation: O, has_people: bool) -> anyhow::Result<()>
+    where
+        O: CompaniesHousePublicDataOperation,
+    {
+        let mut body = match self.client.send(Raw(operation)).await {
+            Ok(body) => body,
+            Err(CompaniesHousePublicDataOperationError::Status(status)) => {
+                eprintln!("Skipping {file}: {status}");
+                return Ok(());
+            }
+            Err(err) => anyhow::bail!("{file}: {err}"),
+        };
+        if let Err(err) = serde_json::from_value::<O::Data>(body.clone()) {
+            eprintln!("{file} does not parse as {}: {err}", O::PATH_TEMPLATE);
+        }
+
+        if has_people {
+            redact(&mut body);
+        }
+        let json = serde_json::to_string_pretty(&body)?
+            .replace(self.company_number.as_str(), COMPANY_NUMBER);
+        std::fs::write(self.fixtures.join(file), json + "\n")?;
+        println!("Recorded {file}");
+        Ok(())
+    }
+}
+
+fn redact(value: &mut Value) {
+    match value {
+        Value::Object(members) => {
+            for (name, value) in members {
+                match name.as_str() {
+                    "date_of_birth" => redact_date_of_birth(value),
+                    name if PERSONAL_MEMBERS.contains(&name) => redact_all(value),
+                    _ => redact(value),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+fn redact_all(value: &mut Value) {
+    match value {
+        Value::String(value) => *value = "REDACTED".to_owned(),
+        Value::Number(_) => *value = Value::from(1),
+        Value::Object(members) => members.values_mut().for_each(redact_all),
+        Value::Array(items) => items.iter_mut().for_each(redact_all),
+        _ => {}
+    }
+}
+
+/// Replace a date of birth with a placeholder of the same shape, either a
+/// `YYYY-MM-DD` date or the `{day, month, year}` object of officers and PSCs,
+/// so the redacted fixtures still parse.
+fn redact_date_of_birth(value: &mut Value) {
+    match value {
+        Value::String(date) => *date = "1970-01-01".to_owned(),
+        Value::Object(members) => {
+            let has_day = members.contains_key("day");
+            *value = json!({ "month": 1, "year": 1970 });
+            if has_day {
+                value["day"] = Value::from(1);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    dotenvy::from_filename(".env.local")?;
+
+    let api_key = std::env::var("COMPANIES_HOUSE_API_KEY")?;
+    let mut args = std::env::args().skip(1);
+    let (Some(company_number), Some(query)) = (args.next(), args.next()) else {
+        anyhow::bail!("usage: record_fixtures <company_number> <search term>");
+    };
+    // The API returns the normalised form, so that is what gets replaced.
+    let company_number = CompanyNumber::new(&company_number)?;
+
+    let recorder = Recorder {
+        client: CompaniesHousePublicDataClient::new(&api_key)?,
+        fixtures: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/test_util/fixtures"),
+        company_number,
+    };
+    let company_number = &recorder.company_number;
+
+    recorder
+        .record(
+            "company_profile.json",
+            GetCompanyProfile::builder()
+                .company_number(company_number)
+                .build(),
+            false,
+        )
+        .await?;
+    recorder
+        .record(
+            "registered_office_address.json",
+            GetCompanyRegisteredOfficeAddress::builder()
+                .company_number(company_number)
+                .build(),
+            false,
+        )
+        .await?;
+    recorder
+        .record(
+            "officer_list.json",
+            ListCompanyOfficers::builder()
+                .company_number(company_number)
+                .build(),
+            true,
+        )
+        .await?;
+    recorder
+        .record(
+            "filing_history.json",
+            ListCompanyFilingHistory::builder()
+                .company_number(company_number)
+                .items_per_page(Some(3))
+                .build(),
+            false,
+        )
+        .await?;
+    recorder
+        .record(
+            "charge_list.json",
+            ListCompanyCharges::builder()
+                .company_number(company_number)
+                .build(),
+            false,
+        )
+        .await?;
+    recorder
+        .record(
+            "persons_with_significant_control_list.json",
+            ListCompanyPersonsWithSignificantControl::builder()
+                .company_number(company_number)
+                .build(),
+            true,
+        )
+        .await?;
+    recorder
+        .record(
+            "company_insolvency.json",
+            GetCompanyInsolvency::builder()
+                .company_number(company_number)
+                .build(),
+            true,
+        )
+        .await?;
+    recorder
+        .record(
+            "company_registers.json",
+            GetCompanyRegisters::builder()
+                .company_number(company_number)
+                .build(),
+            false,
+        )
+        .await?;
+    recorder
+        .record(
+            "company_search.json",
+            SearchCompanies::builder()
+                .query(&query)
+                .items_per_page(Some(3))
+                .build(),
+            false,
+        )
+        .await?;
+    recorder
+        .record(
+            "officer_search.json",
+            SearchOfficers::builder()
+                .query(&query)
+                .items_per_page(Some(3))
+                .build(),
+            true,
+        )
+        .await?;
+    recorder
+        .record(
+            "disqualified_officer_search.json",
+            SearchDisqualifiedOfficers::builder()
+                .query(&query)
+                .items_per_page(Some(3))
+                .build(),
+            true,
+        )
+        .await?;
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use companies_house_api::{operation, CompaniesHousePublicDataClient};
+use companies_house_api::{operation, types::CompanyNumber, CompaniesHousePublicDataClient};
 use derive_more::derive::Display;
 use minus::dynamic_paging;
 use std::fmt::Write;
@@ -31,8 +31,8 @@ fn display_result<T: std::fmt::Debug, E: std::error::Error>(
     Ok(())
 }
 
-fn get_company_number() -> anyhow::Result<String> {
-    Ok(inquire::Text::new("Enter a company number:").prompt()?)
+fn get_company_number() -> anyhow::Result<CompanyNumber> {
+    Ok(inquire::CustomType::new("Enter a company number:").prompt()?)
 }
 
 fn get_search_term() -> anyhow::Result<String> {
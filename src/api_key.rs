@@ -0,0 +1,222 @@
+//! Where clients get their API key from, asked again for every request or
+//! stream connection, so keys can be swapped without rebuilding a client.
+
+use std::{
+    env,
+    fmt::Debug,
+    future::Future,
+    io,
+    path::PathBuf,
+    pin::Pin,
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, Instant, SystemTime},
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ApiKeyError {
+    #[error("Environment variable {name} is not set")]
+    MissingEnv { name: String },
+    #[error("Unable to read API key from {path:?}")]
+    ReadFile {
+        path: PathBuf,
+        #[source]
+        inner: io::Error,
+    },
+    #[error("API key is empty")]
+    Empty,
+    #[error("API key contains characters which are not allowed in a header")]
+    Invalid,
+    #[error(transparent)]
+    Other(Box<dyn std::error::Error + Send + Sync>),
+}
+
+pub type ApiKeyFuture<'a> = Pin<Box<dyn Future<Output = Result<String, ApiKeyError>> + Send + 'a>>;
+
+/// Supplies the API key for each request.
+pub trait ApiKeyProvider: Send + Sync + Debug {
+    fn api_key(&self) -> ApiKeyFuture<'_>;
+}
+
+/// The same key for every request.
+#[derive(Clone)]
+pub struct StaticApiKey(String);
+
+impl StaticApiKey {
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self(api_key.into())
+    }
+}
+
+impl Debug for StaticApiKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("StaticApiKey(..)")
+    }
+}
+
+impl ApiKeyProvider for StaticApiKey {
+    fn api_key(&self) -> ApiKeyFuture<'_> {
+        Box::pin(async { Ok(self.0.clone()) })
+    }
+}
+
+/// A key which can be replaced at runtime with [`set`](Self::set), through
+/// any clone of it.
+#[derive(Clone)]
+pub struct SharedApiKey(Arc<RwLock<String>>);
+
+impl SharedApiKey {
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self(Arc::new(RwLock::new(api_key.into())))
+    }
+
+    pub fn set(&self, api_key: impl Into<String>) {
+        *self.0.write().unwrap() = api_key.into();
+    }
+}
+
+impl Debug for SharedApiKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SharedApiKey(..)")
+    }
+}
+
+impl ApiKeyProvider for SharedApiKey {
+    fn api_key(&self) -> ApiKeyFuture<'_> {
+        Box::pin(async { Ok(self.0.read().unwrap().clone()) })
+    }
+}
+
+/// The value of an environment variable, read for every request.
+#[derive(Debug, Clone)]
+pub struct EnvApiKey {
+    name: String,
+}
+
+impl EnvApiKey {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl ApiKeyProvider for EnvApiKey {
+    fn api_key(&self) -> ApiKeyFuture<'_> {
+        Box::pin(async {
+            env::var(&self.name).map_err(|_| ApiKeyError::MissingEnv {
+                name: self.name.clone(),
+            })
+        })
+    }
+}
+
+/// The trimmed contents of a file, e.g. a mounted secret, read again when its
+/// modification time changes.
+///
+/// The file is checked at most once per [`check_interval`](Self::check_interval),
+/// so requests in between use the cached key without touching the filesystem.
+/// Checks go through `tokio::fs`, so they don't block the runtime's workers.
+#[derive(Debug)]
+pub struct FileApiKey {
+    path: PathBuf,
+    check_interval: Duration,
+    cached: Mutex<Option<CachedFile>>,
+}
+
+#[derive(Debug)]
+struct CachedFile {
+    checked_at: Instant,
+    modified: SystemTime,
+    api_key: String,
+}
+
+impl FileApiKey {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            check_interval: Duration::from_secs(10),
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// How often to check the file for changes, 10 seconds by default.
+    pub fn check_interval(self, check_interval: Duration) -> Self {
+        Self {
+            check_interval,
+            ..self
+        }
+    }
+
+    async fn read(&self) -> Result<String, ApiKeyError> {
+        let now = Instant::now();
+        if let Some(cached) = &*self.cached.lock().unwrap() {
+            if now.duration_since(cached.checked_at) < self.check_interval {
+                return Ok(cached.api_key.clone());
+            }
+        }
+
+        let read_error = |inner| ApiKeyError::ReadFile {
+            path: self.path.clone(),
+            inner,
+        };
+        let modified = tokio::fs::metadata(&self.path)
+            .await
+            .and_then(|metadata| metadata.modified())
+            .map_err(read_error)?;
+        if let Some(cached) = &mut *self.cached.lock().unwrap() {
+            if cached.modified == modified {
+                cached.checked_at = now;
+                return Ok(cached.api_key.clone());
+            }
+        }
+
+        let api_key = tokio::fs::read_to_string(&self.path)
+            .await
+            .map_err(read_error)?
+            .trim()
+            .to_owned();
+        if api_key.is_empty() {
+            return Err(ApiKeyError::Empty);
+        }
+        *self.cached.lock().unwrap() = Some(CachedFile {
+            checked_at: now,
+            modified,
+            api_key: api_key.clone(),
+        });
+        Ok(api_key)
+    }
+}
+
+impl ApiKeyProvider for FileApiKey {
+    fn api_key(&self) -> ApiKeyFuture<'_> {
+        Box::pin(self.read())
+    }
+}
+
+/// A key from an async function, e.g. fetched from a secrets manager.
+pub struct ApiKeyCallback<F>(F);
+
+impl<F, Fut> ApiKeyCallback<F>
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<String, ApiKeyError>> + Send + 'static,
+{
+    pub fn new(callback: F) -> Self {
+        Self(callback)
+    }
+}
+
+impl<F> Debug for ApiKeyCallback<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ApiKeyCallback(..)")
+    }
+}
+
+impl<F, Fut> ApiKeyProvider for ApiKeyCallback<F>
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<String, ApiKeyError>> + Send + 'static,
+{
+    fn api_key(&self) -> ApiKeyFuture<'_> {
+        Box::pin((self.0)())
+    }
+}
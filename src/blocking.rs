@@ -0,0 +1,263 @@
+//! Synchronous wrappers around the async clients, for use outside of an async
+//! runtime.
+//!
+//! Each client owns a single threaded tokio runtime and blocks on the async
+//! client's futures, so the same operations can be sent. Calling these methods
+//! from within an async runtime will panic.
+
+use std::{io, sync::Arc};
+use tokio::runtime::Runtime;
+
+#[cfg(feature = "streaming")]
+use crate::streaming::{
+    self,
+    operation::{CompaniesHouseStreamingOperation, StreamItem},
+    CompaniesHouseStreamingConnectionError, CompaniesHouseStreamingNextError, StreamRecorder,
+};
+use crate::{api_key::ApiKeyProvider, UnknownFieldReporter};
+#[cfg(feature = "public-data")]
+use crate::{
+    operation::{CompaniesHousePublicDataOperation, CompaniesHousePublicDataOperationError},
+    transport::HttpTransport,
+    ApiKeyPool, CompaniesHousePublicDataClientError,
+};
+#[cfg(feature = "streaming")]
+use std::time::Duration;
+
+fn runtime() -> io::Result<Arc<Runtime>> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map(Arc::new)
+}
+
+/// Blocking version of [`crate::CompaniesHousePublicDataClient`]. Clones share
+/// the client and its runtime.
+#[cfg(feature = "public-data")]
+#[derive(Clone)]
+pub struct CompaniesHousePublicDataClient {
+    inner: crate::CompaniesHousePublicDataClient,
+    runtime: Arc<Runtime>,
+}
+
+#[cfg(feature = "public-data")]
+impl CompaniesHousePublicDataClient {
+    pub fn new(api_key: &str) -> Result<Self, CompaniesHousePublicDataClientError> {
+        Ok(Self {
+            inner: crate::CompaniesHousePublicDataClient::new(api_key)?,
+            runtime: runtime().map_err(CompaniesHousePublicDataClientError::Runtime)?,
+        })
+    }
+
+    /// Ask `provider` for the API key for every request.
+    pub fn with_api_key_provider(
+        provider: impl ApiKeyProvider + 'static,
+    ) -> Result<Self, CompaniesHousePublicDataClientError> {
+        Ok(Self {
+            inner: crate::CompaniesHousePublicDataClient::with_api_key_provider(provider)?,
+            runtime: runtime().map_err(CompaniesHousePublicDataClientError::Runtime)?,
+        })
+    }
+
+    /// Spread requests across several API keys.
+    pub fn with_api_key_pool(
+        api_keys: ApiKeyPool,
+    ) -> Result<Self, CompaniesHousePublicDataClientError> {
+        Ok(Self {
+            inner: crate::CompaniesHousePublicDataClient::with_api_key_pool(api_keys)?,
+            runtime: runtime().map_err(CompaniesHousePublicDataClientError::Runtime)?,
+        })
+    }
+
+    /// The client's API keys, e.g. to check their usage.
+    pub fn api_key_pool(&self) -> &ApiKeyPool {
+        self.inner.api_key_pool()
+    }
+
+    /// Send requests to a different host, e.g. a mock server in tests.
+    pub fn base_url(self, base_url: &str) -> Result<Self, CompaniesHousePublicDataClientError> {
+        Ok(Self {
+            inner: self.inner.base_url(base_url)?,
+            runtime: self.runtime,
+        })
+    }
+
+    /// Report response fields which are not modelled by this crate.
+    pub fn report_unknown_fields(self, reporter: UnknownFieldReporter) -> Self {
+        Self {
+            inner: self.inner.report_unknown_fields(reporter),
+            runtime: self.runtime,
+        }
+    }
+
+    /// Send requests with a different HTTP transport instead of the default
+    /// reqwest one.
+    pub fn transport(self, transport: impl HttpTransport + 'static) -> Self {
+        Self {
+            inner: self.inner.transport(transport),
+            runtime: self.runtime,
+        }
+    }
+
+    /// Send requests with a configured [`reqwest::Client`].
+    pub fn http_client(self, client: reqwest::Client) -> Self {
+        Self {
+            inner: self.inner.http_client(client),
+            runtime: self.runtime,
+        }
+    }
+
+    pub fn rotate_client(&mut self) -> Result<(), CompaniesHousePublicDataClientError> {
+        self.inner.rotate_client()
+    }
+
+    pub fn send<T: CompaniesHousePublicDataOperation>(
+        &self,
+        operation: T,
+    ) -> Result<T::Data, CompaniesHousePublicDataOperationError<T::StatusError>> {
+        self.runtime.block_on(self.inner.send(operation))
+    }
+}
+
+/// Blocking version of [`streaming::CompaniesHouseStreamingClient`]. Clones
+/// share the client and its runtime.
+#[cfg(feature = "streaming")]
+#[derive(Clone)]
+pub struct CompaniesHouseStreamingClient {
+    inner: streaming::CompaniesHouseStreamingClient,
+    runtime: Arc<Runtime>,
+}
+
+#[cfg(feature = "streaming")]
+impl CompaniesHouseStreamingClient {
+    pub fn new(api_key: &str) -> io::Result<Self> {
+        Ok(Self {
+            inner: streaming::CompaniesHouseStreamingClient::new(api_key),
+            runtime: runtime()?,
+        })
+    }
+
+    /// Ask `provider` for the API key for every connection.
+    pub fn with_api_key_provider(provider: impl ApiKeyProvider + 'static) -> io::Result<Self> {
+        Ok(Self {
+            inner: streaming::CompaniesHouseStreamingClient::with_api_key_provider(provider),
+            runtime: runtime()?,
+        })
+    }
+
+    fn map(
+        self,
+        f: impl FnOnce(
+            streaming::CompaniesHouseStreamingClient,
+        ) -> streaming::CompaniesHouseStreamingClient,
+    ) -> Self {
+        Self {
+            inner: f(self.inner),
+            runtime: self.runtime,
+        }
+    }
+
+    /// Connect with a configured [`reqwest::Client`], see
+    /// [`streaming::CompaniesHouseStreamingClient::http_client`].
+    pub fn http_client(self, http_client: reqwest::Client) -> Self {
+        self.map(|inner| inner.http_client(http_client))
+    }
+
+    /// Connect to a different host, e.g. a mock server in tests.
+    pub fn base_url(self, base_url: impl Into<String>) -> Self {
+        self.map(|inner| inner.base_url(base_url))
+    }
+
+    pub fn connection_timeout(self, duration: Duration) -> Self {
+        self.map(|inner| inner.connection_timeout(duration))
+    }
+
+    pub fn chunk_timeout(self, duration: Duration) -> Self {
+        self.map(|inner| inner.chunk_timeout(duration))
+    }
+
+    /// Maximum size in bytes of a single stream item, see
+    /// [`streaming::CompaniesHouseStreamingClient::max_line_length`].
+    pub fn max_line_length(self, max_line_length: usize) -> Self {
+        self.map(|inner| inner.max_line_length(max_line_length))
+    }
+
+    /// Report fields in stream items which are not modelled by this crate.
+    pub fn report_unknown_fields(self, reporter: UnknownFieldReporter) -> Self {
+        self.map(|inner| inner.report_unknown_fields(reporter))
+    }
+
+    pub fn stream<O: CompaniesHouseStreamingOperation>(
+        &self,
+        operation: O,
+        timepoint: Option<usize>,
+    ) -> Result<StreamConnection<O>, CompaniesHouseStreamingConnectionError> {
+        Ok(StreamConnection {
+            inner: self
+                .runtime
+                .block_on(self.inner.stream(operation, timepoint))?,
+            runtime: self.runtime.clone(),
+            done: false,
+        })
+    }
+}
+
+/// Blocking version of [`streaming::StreamConnection`].
+///
+/// Iterating yields items until the stream completes, so errors other than
+/// [`CompaniesHouseStreamingNextError::StreamComplete`] are yielded and
+/// iteration can continue after them where the connection allows. Iteration
+/// ends after an error which closes the connection, such as
+/// [`CompaniesHouseStreamingNextError::ChunkTimeout`].
+#[cfg(feature = "streaming")]
+pub struct StreamConnection<O: CompaniesHouseStreamingOperation> {
+    inner: streaming::StreamConnection<O>,
+    runtime: Arc<Runtime>,
+    done: bool,
+}
+
+#[cfg(feature = "streaming")]
+impl<O: CompaniesHouseStreamingOperation> StreamConnection<O> {
+    /// Tee every raw stream item read from this connection into a recorder.
+    pub fn with_recorder(self, recorder: StreamRecorder) -> Self {
+        Self {
+            inner: self.inner.with_recorder(recorder),
+            runtime: self.runtime,
+            done: self.done,
+        }
+    }
+
+    /// Stop recording and return the recorder, e.g. to finish it.
+    pub fn take_recorder(&mut self) -> Option<StreamRecorder> {
+        self.inner.take_recorder()
+    }
+
+    pub fn next_item(&mut self) -> Result<StreamItem<O::Data>, CompaniesHouseStreamingNextError> {
+        self.runtime.block_on(self.inner.next())
+    }
+}
+
+#[cfg(feature = "streaming")]
+impl<O: CompaniesHouseStreamingOperation> Iterator for StreamConnection<O> {
+    type Item = Result<StreamItem<O::Data>, CompaniesHouseStreamingNextError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_item() {
+            Err(CompaniesHouseStreamingNextError::StreamComplete) => {
+                self.done = true;
+                None
+            }
+            Err(
+                err @ (CompaniesHouseStreamingNextError::ChunkTimeout
+                | CompaniesHouseStreamingNextError::BadChunk(_)),
+            ) => {
+                self.done = true;
+                Some(Err(err))
+            }
+            result => Some(result),
+        }
+    }
+}
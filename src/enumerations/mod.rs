@@ -0,0 +1,107 @@
+//! Human readable descriptions of API values, embedded from the Companies House
+//! [api-enumerations](https://github.com/companieshouse/api-enumerations) data.
+//!
+//! The YAML vendored in `enumerations/` is converted to JSON with
+//! `cargo xtask enumerations`.
+
+use serde_json::{Map, Value};
+use std::{collections::HashMap, sync::OnceLock};
+
+use crate::types::shared::{AsDate, Date};
+
+type Sections = HashMap<String, HashMap<String, String>>;
+
+fn load(cell: &'static OnceLock<Sections>, json: &str) -> &'static Sections {
+    cell.get_or_init(|| serde_json::from_str(json).expect("embedded enumerations are valid JSON"))
+}
+
+fn constants() -> &'static Sections {
+    static CONSTANTS: OnceLock<Sections> = OnceLock::new();
+    load(&CONSTANTS, include_str!("constants.json"))
+}
+
+fn filing_history_descriptions() -> &'static Sections {
+    static DESCRIPTIONS: OnceLock<Sections> = OnceLock::new();
+    load(
+        &DESCRIPTIONS,
+        include_str!("filing_history_descriptions.json"),
+    )
+}
+
+/// Look up a value in a section of the embedded `constants.json`, e.g. `("company_status", "active")`.
+pub fn constant(section: &str, key: &str) -> Option<&'static str> {
+    constants().get(section)?.get(key).map(String::as_str)
+}
+
+/// The template for a filing history description key, with `{placeholders}`
+/// for its description values and `**` around the emphasised part.
+pub fn filing_history_description(key: &str) -> Option<&'static str> {
+    filing_history_descriptions()
+        .get("description")?
+        .get(key)
+        .map(String::as_str)
+}
+
+/// Expand a filing history description key into readable text, substituting
+/// `values` into its placeholders. Dates are written out in full, e.g.
+/// `1 January 2024`, and `**` emphasis markers are removed.
+///
+/// Returns `None` if the key is not known. Placeholders without a value are
+/// left in place.
+pub fn format_filing_history_description(
+    key: &str,
+    values: Option<&Map<String, Value>>,
+) -> Option<String> {
+    let template = filing_history_description(key)?.replace("**", "");
+
+    let mut formatted = String::with_capacity(template.len());
+    let mut rest = template.as_str();
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}').map(|end| start + end) else {
+            break;
+        };
+        formatted.push_str(&rest[..start]);
+        let name = &rest[start + 1..end];
+        match values.and_then(|values| values.get(name)) {
+            Some(value) => formatted.push_str(&format_value(value)),
+            None => formatted.push_str(&rest[start..=end]),
+        }
+        rest = &rest[end + 1..];
+    }
+    formatted.push_str(rest);
+
+    Some(formatted)
+}
+
+fn format_value(value: &Value) -> String {
+    match value {
+        Value::String(value) => match value.as_date() {
+            Some(date) => format_date(date),
+            None => value.to_owned(),
+        },
+        value => value.to_string(),
+    }
+}
+
+fn format_date(date: Date) -> String {
+    const MONTHS: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+    format!(
+        "{} {} {}",
+        date.day(),
+        MONTHS[date.month() as usize - 1],
+        date.year()
+    )
+}
@@ -1,4 +1,22 @@
+#[cfg(any(feature = "public-data", feature = "streaming"))]
+pub mod api_key;
+#[cfg(all(
+    feature = "blocking",
+    any(feature = "public-data", feature = "streaming")
+))]
+pub mod blocking;
+pub mod enumerations;
+mod macros;
 mod public_data;
+#[cfg(feature = "streaming")]
 pub mod streaming;
+#[cfg(feature = "metrics")]
+pub mod telemetry;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+#[cfg(any(feature = "public-data", feature = "streaming"))]
+mod unknown_fields;
 
 pub use public_data::*;
+#[cfg(any(feature = "public-data", feature = "streaming"))]
+pub use unknown_fields::{UnknownField, UnknownFieldReporter};
@@ -0,0 +1,217 @@
+/// Defines an enum of the string values the API is known to send, with an
+/// `Unknown` fallback variant so that newly added values never fail to parse.
+///
+/// The raw value is kept in `Unknown` and serialized back unchanged.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            /// A value not known to this version of the crate.
+            Unknown(String),
+        }
+
+        impl $name {
+            /// The value as sent by the API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Unknown(value) => value,
+                }
+            }
+
+            /// Whether this is a value not known to this version of the crate.
+            pub fn is_unknown(&self) -> bool {
+                matches!(self, Self::Unknown(_))
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $($value => Self::$variant,)*
+                    _ => Self::Unknown(value),
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => Self::$variant,)*
+                    _ => Self::Unknown(value.to_owned()),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <String as serde::Deserialize>::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+}
+
+/// Adds a `description` method to a [`string_enum!`] looking up its value in a
+/// section of the embedded `constants.json` enumerations.
+macro_rules! describe_enum {
+    ($name:ident, $section:literal) => {
+        impl $name {
+            #[doc = concat!("Human readable description from the `", $section, "` enumerations.")]
+            pub fn description(&self) -> Option<&'static str> {
+                crate::enumerations::constant($section, self.as_str())
+            }
+        }
+    };
+}
+
+/// Defines a public data operation: the builder struct, its status error enum
+/// and the [`CompaniesHousePublicDataOperation`] impl.
+///
+/// ```ignore
+/// operation! {
+///     /// Get the basic company information
+///     pub struct GetCompanyProfile {
+///         #[builder(setter(into))]
+///         company_number: CompanyNumber,
+///     }
+///
+///     GET ["company", company_number] => CompanyProfile;
+///
+///     pub enum GetCompanyProfileStatusError {
+///         NOT_FOUND => NotFound("Not found"),
+///     }
+/// }
+/// ```
+///
+/// Path segments are string literals or fields implementing `AsRef<str>`, and
+/// are percent-encoded with [`endpoint_url`] when the request is sent. An
+/// optional `query { "name" => field }` block before the error enum adds query
+/// parameters from fields implementing [`QueryParam`], omitting those which are
+/// `None`. Every operation maps `200` to success, `401` to `Unauthorized` and
+/// other statuses to `UnexpectedStatus`.
+///
+/// [`CompaniesHousePublicDataOperation`]: crate::operation::CompaniesHousePublicDataOperation
+/// [`endpoint_url`]: crate::operation::endpoint_url
+/// [`QueryParam`]: crate::operation::QueryParam
+#[cfg(feature = "public-data")]
+macro_rules! operation {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident: $field_ty:ty,
+            )*
+        }
+
+        $method:ident [$($segment:tt),+ $(,)?] => $data:ty;
+
+        $(query {
+            $($param:literal => $param_field:ident),* $(,)?
+        })?
+
+        $(#[$error_meta:meta])*
+        pub enum $error:ident {
+            $($status:ident => $variant:ident($message:literal)),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(typed_builder::TypedBuilder)]
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                $field: $field_ty,
+            )*
+        }
+
+        $(#[$error_meta])*
+        #[derive(Debug, thiserror::Error)]
+        pub enum $error {
+            #[error("Unauthorized")]
+            Unauthorized,
+            $(
+                #[error($message)]
+                $variant,
+            )*
+            #[error(transparent)]
+            UnexpectedStatus(#[from] crate::unexpected_status::UnexpectedStatusError),
+        }
+
+        impl crate::operation::CompaniesHousePublicDataOperation for $name {
+            type StatusError = $error;
+            type Data = $data;
+
+            const PATH_TEMPLATE: &'static str =
+                concat!($("/", operation!(@template $segment)),+);
+
+            fn build_request(&self) -> crate::transport::OperationRequest {
+                let query: Vec<(String, Option<String>)> = Vec::from([
+                    $($(($param.to_owned(), crate::operation::QueryParam::query_value(&self.$param_field))),*)?
+                ]);
+
+                crate::transport::OperationRequest {
+                    method: reqwest::Method::$method,
+                    path: Vec::from([$(operation!(@segment self $segment).to_owned()),+]),
+                    query: query
+                        .into_iter()
+                        .filter_map(|(name, value)| Some((name, value?)))
+                        .collect(),
+                    headers: reqwest::header::HeaderMap::new(),
+                }
+            }
+
+            fn handle_status(&self, status_code: reqwest::StatusCode) -> Result<(), Self::StatusError> {
+                match status_code {
+                    reqwest::StatusCode::OK => Ok(()),
+                    reqwest::StatusCode::UNAUTHORIZED => Err($error::Unauthorized),
+                    $(reqwest::StatusCode::$status => Err($error::$variant),)*
+                    status_code => Err($error::UnexpectedStatus(
+                        crate::unexpected_status::UnexpectedStatusError { status_code },
+                    )),
+                }
+            }
+        }
+    };
+    (@template $segment:literal) => {
+        $segment
+    };
+    (@template $field:ident) => {
+        concat!("{", stringify!($field), "}")
+    };
+    (@segment $self:ident $segment:literal) => {
+        $segment
+    };
+    (@segment $self:ident $field:ident) => {
+        AsRef::<str>::as_ref(&$self.$field)
+    };
+}
+
+pub(crate) use describe_enum;
+#[cfg(feature = "public-data")]
+pub(crate) use operation;
+pub(crate) use string_enum;
@@ -0,0 +1,280 @@
+use reqwest::header::HeaderValue;
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use super::CompaniesHousePublicDataClientError;
+use crate::api_key::{ApiKeyProvider, StaticApiKey};
+
+/// How long a key is skipped after a `401 Unauthorized` response by default.
+const DEFAULT_QUARANTINE: Duration = Duration::from_secs(5 * 60);
+
+/// How [`ApiKeyPool`] picks the key for each request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ApiKeySelection {
+    /// Use each available key in turn.
+    #[default]
+    RoundRobin,
+    /// Use the available key with the fewest requests in its current rate
+    /// limit window, or overall without a rate limit.
+    LeastUsed,
+}
+
+/// Usage of a single key in an [`ApiKeyPool`], by its position in the pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiKeyStats {
+    pub index: usize,
+    /// Requests sent with this key since the pool was created.
+    pub requests: u64,
+    /// Requests sent with this key in its current rate limit window.
+    pub window_requests: u32,
+    /// Whether the key is quarantined after a `401 Unauthorized` response.
+    pub quarantined: bool,
+}
+
+/// Several API keys to spread requests across, for use with
+/// [`CompaniesHousePublicDataClient::with_api_key_pool`].
+///
+/// Keys which get a `401 Unauthorized` response are quarantined and skipped
+/// for [`quarantine_for`](Self::quarantine_for), as long as another key is
+/// left, so a key from an [`ApiKeyProvider`] which has since been rotated is
+/// tried again. With [`rate_limit`](Self::rate_limit) set, keys which have used up
+/// their window, or get a `429 Too Many Requests` response, are skipped until
+/// the window ends.
+///
+/// [`CompaniesHousePublicDataClient::with_api_key_pool`]: crate::CompaniesHousePublicDataClient::with_api_key_pool
+#[derive(Debug)]
+pub struct ApiKeyPool {
+    keys: Vec<Arc<dyn ApiKeyProvider>>,
+    selection: ApiKeySelection,
+    rate_limit: Option<(u32, Duration)>,
+    quarantine: Duration,
+    state: Mutex<PoolState>,
+}
+
+#[derive(Debug)]
+struct PoolState {
+    next: usize,
+    keys: Vec<KeyState>,
+}
+
+#[derive(Debug, Clone)]
+struct KeyState {
+    requests: u64,
+    window_start: Instant,
+    window_requests: u32,
+    quarantined_at: Option<Instant>,
+}
+
+/// A key chosen for one request.
+#[derive(Debug, Clone)]
+pub(crate) struct ApiKeyLease {
+    pub(crate) index: usize,
+    pub(crate) provider: Arc<dyn ApiKeyProvider>,
+}
+
+impl ApiKeyPool {
+    pub fn new<K: AsRef<str>>(
+        api_keys: impl IntoIterator<Item = K>,
+    ) -> Result<Self, CompaniesHousePublicDataClientError> {
+        let keys = api_keys
+            .into_iter()
+            .map(|api_key| {
+                let api_key = api_key.as_ref();
+                HeaderValue::from_str(api_key)
+                    .map_err(|_| CompaniesHousePublicDataClientError::BadApiKey)?;
+                Ok(Arc::new(StaticApiKey::new(api_key)) as Arc<dyn ApiKeyProvider>)
+            })
+            .collect::<Result<Vec<_>, CompaniesHousePublicDataClientError>>()?;
+        Self::from_providers(keys)
+    }
+
+    /// A pool of keys which may change at runtime, e.g. one [`FileApiKey`]
+    /// per key.
+    ///
+    /// [`FileApiKey`]: crate::api_key::FileApiKey
+    pub fn from_providers(
+        providers: impl IntoIterator<Item = Arc<dyn ApiKeyProvider>>,
+    ) -> Result<Self, CompaniesHousePublicDataClientError> {
+        let keys: Vec<_> = providers.into_iter().collect();
+        if keys.is_empty() {
+            return Err(CompaniesHousePublicDataClientError::NoApiKeys);
+        }
+
+        let now = Instant::now();
+        let state = KeyState {
+            requests: 0,
+            window_start: now,
+            window_requests: 0,
+            quarantined_at: None,
+        };
+        Ok(Self {
+            state: Mutex::new(PoolState {
+                next: 0,
+                keys: vec![state; keys.len()],
+            }),
+            keys,
+            selection: ApiKeySelection::default(),
+            rate_limit: None,
+            quarantine: DEFAULT_QUARANTINE,
+        })
+    }
+
+    pub fn selection(self, selection: ApiKeySelection) -> Self {
+        Self { selection, ..self }
+    }
+
+    /// Allow each key at most `requests` in every `window`, e.g. the Companies
+    /// House default of 600 requests per 5 minutes.
+    pub fn rate_limit(self, requests: u32, window: Duration) -> Self {
+        Self {
+            rate_limit: Some((requests, window)),
+            ..self
+        }
+    }
+
+    /// Return quarantined keys to the pool after `duration`, 5 minutes by
+    /// default.
+    pub fn quarantine_for(self, duration: Duration) -> Self {
+        Self {
+            quarantine: duration,
+            ..self
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn stats(&self) -> Vec<ApiKeyStats> {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        state
+            .keys
+            .iter_mut()
+            .enumerate()
+            .map(|(index, key)| {
+                self.refresh(key, now);
+                ApiKeyStats {
+                    index,
+                    requests: key.requests,
+                    window_requests: key.window_requests,
+                    quarantined: key.quarantined_at.is_some(),
+                }
+            })
+            .collect()
+    }
+
+    /// Start a new rate limit window or end a quarantine if they have elapsed.
+    fn refresh(&self, key: &mut KeyState, now: Instant) {
+        if let Some((_, window)) = self.rate_limit {
+            if now.duration_since(key.window_start) >= window {
+                key.window_start = now;
+                key.window_requests = 0;
+            }
+        }
+        if let Some(quarantined_at) = key.quarantined_at {
+            if now.duration_since(quarantined_at) >= self.quarantine {
+                key.quarantined_at = None;
+            }
+        }
+    }
+
+    fn is_available(&self, key: &KeyState) -> bool {
+        key.quarantined_at.is_none()
+            && self
+                .rate_limit
+                .map_or(true, |(requests, _)| key.window_requests < requests)
+    }
+
+    /// Pick a key for the next request and count it against that key.
+    pub(crate) fn acquire(&self) -> Result<ApiKeyLease, ApiKeyPoolExhausted> {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        for key in &mut state.keys {
+            self.refresh(key, now);
+        }
+
+        let count = state.keys.len();
+        let index = match self.selection {
+            ApiKeySelection::RoundRobin => (0..count)
+                .map(|offset| (state.next + offset) % count)
+                .find(|&index| self.is_available(&state.keys[index])),
+            ApiKeySelection::LeastUsed => (0..count)
+                .filter(|&index| self.is_available(&state.keys[index]))
+                .min_by_key(|&index| {
+                    let key = &state.keys[index];
+                    match self.rate_limit {
+                        Some(_) => key.window_requests as u64,
+                        None => key.requests,
+                    }
+                }),
+        };
+
+        let Some(index) = index else {
+            return Err(ApiKeyPoolExhausted {
+                retry_after: self.retry_after(&state.keys, now),
+            });
+        };
+
+        state.next = (index + 1) % count;
+        let key = &mut state.keys[index];
+        key.requests += 1;
+        key.window_requests += 1;
+        Ok(ApiKeyLease {
+            index,
+            provider: self.keys[index].clone(),
+        })
+    }
+
+    /// How long until any key becomes available, if one ever will.
+    fn retry_after(&self, keys: &[KeyState], now: Instant) -> Option<Duration> {
+        keys.iter()
+            .filter_map(|key| match key.quarantined_at {
+                Some(quarantined_at) => Some(
+                    self.quarantine
+                        .saturating_sub(now.duration_since(quarantined_at)),
+                ),
+                None => Some(
+                    self.rate_limit?
+                        .1
+                        .saturating_sub(now.duration_since(key.window_start)),
+                ),
+            })
+            .min()
+    }
+
+    /// Skip the key after a `401 Unauthorized` response, unless it is the only
+    /// key left, so requests keep reporting `Unauthorized` rather than failing
+    /// to find a key.
+    pub(crate) fn quarantine(&self, lease: &ApiKeyLease) {
+        let mut state = self.state.lock().unwrap();
+        let others = (state.keys.iter().enumerate())
+            .any(|(index, key)| index != lease.index && key.quarantined_at.is_none());
+        if others {
+            log::warn!(index = lease.index; "Quarantining API key after 401 Unauthorized");
+            state.keys[lease.index].quarantined_at = Some(Instant::now());
+        }
+    }
+
+    /// Skip the key until its window ends after a `429 Too Many Requests` response.
+    pub(crate) fn exhaust(&self, lease: &ApiKeyLease) {
+        if let Some((requests, _)) = self.rate_limit {
+            let key = &mut self.state.lock().unwrap().keys[lease.index];
+            key.window_requests = key.window_requests.max(requests);
+        }
+    }
+}
+
+/// Every key in the pool is quarantined or rate limited.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("No API key available")]
+pub struct ApiKeyPoolExhausted {
+    /// How long until a key becomes available.
+    pub retry_after: Option<Duration>,
+}
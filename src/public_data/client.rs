@@ -0,0 +1,308 @@
+use reqwest::{
+    header::{self, HeaderValue},
+    StatusCode, Url,
+};
+use std::sync::Arc;
+use thiserror::Error;
+
+#[cfg(feature = "tower")]
+use super::service::{DynTransport, PublicDataService, ServiceTransport};
+#[cfg(any(feature = "tower", feature = "tracing"))]
+use super::transport::HttpResponse;
+#[cfg(feature = "tracing")]
+use super::transport::HttpTransportError;
+use super::{
+    api_key_pool::ApiKeyPool,
+    operation::{CompaniesHousePublicDataOperation, CompaniesHousePublicDataOperationError},
+    transport::{HttpRequest, HttpTransport, ReqwestTransport},
+};
+use crate::{
+    api_key::{ApiKeyError, ApiKeyProvider},
+    unknown_fields::{self, UnknownFieldReporter},
+};
+
+#[derive(Debug, Error)]
+pub enum CompaniesHousePublicDataClientError {
+    #[error("Bad Companies House API key provided")]
+    BadApiKey,
+    #[error("No Companies House API keys provided")]
+    NoApiKeys,
+    #[error("Bad base URL {base_url:?}")]
+    BadBaseUrl { base_url: String },
+    #[error("Unable to construct client")]
+    ReqwestError(#[from] reqwest::Error),
+    /// Only returned by the `blocking` clients.
+    #[error("Unable to start runtime for blocking client")]
+    Runtime(#[source] std::io::Error),
+}
+
+/// A client for the Companies House public data API.
+///
+/// The client is `Send + Sync` and cheap to clone, as clones share its
+/// connection pool and API keys through an internal `Arc`.
+#[derive(Clone)]
+pub struct CompaniesHousePublicDataClient {
+    inner: Arc<ClientInner>,
+}
+
+#[derive(Clone)]
+struct ClientInner {
+    transport: Transport,
+    api_keys: Arc<ApiKeyPool>,
+    base_url: Url,
+    unknown_fields: Option<UnknownFieldReporter>,
+}
+
+#[derive(Clone)]
+enum Transport {
+    Reqwest(ReqwestTransport),
+    Custom(Arc<dyn HttpTransport>),
+}
+
+impl Transport {
+    fn get(&self) -> &dyn HttpTransport {
+        match self {
+            Self::Reqwest(transport) => transport,
+            Self::Custom(transport) => transport.as_ref(),
+        }
+    }
+
+    #[cfg(feature = "tower")]
+    fn into_shared(self) -> Arc<dyn HttpTransport> {
+        match self {
+            Self::Reqwest(transport) => Arc::new(transport),
+            Self::Custom(transport) => transport,
+        }
+    }
+}
+
+const DEFAULT_BASE_URL: &str = "https://api.company-information.service.gov.uk";
+
+impl CompaniesHousePublicDataClient {
+    pub fn new(api_key: &str) -> Result<Self, CompaniesHousePublicDataClientError> {
+        Self::with_api_key_pool(ApiKeyPool::new([api_key])?)
+    }
+
+    /// Ask `provider` for the API key for every request, so the key can change
+    /// at runtime.
+    pub fn with_api_key_provider(
+        provider: impl ApiKeyProvider + 'static,
+    ) -> Result<Self, CompaniesHousePublicDataClientError> {
+        Self::with_api_key_pool(ApiKeyPool::from_providers([
+            Arc::new(provider) as Arc<dyn ApiKeyProvider>
+        ])?)
+    }
+
+    /// Spread requests across several API keys.
+    pub fn with_api_key_pool(
+        api_keys: ApiKeyPool,
+    ) -> Result<Self, CompaniesHousePublicDataClientError> {
+        Ok(CompaniesHousePublicDataClient {
+            inner: Arc::new(ClientInner {
+                transport: Transport::Reqwest(ReqwestTransport::new()?),
+                api_keys: Arc::new(api_keys),
+                base_url: Self::parse_base_url(DEFAULT_BASE_URL)?,
+                unknown_fields: None,
+            }),
+        })
+    }
+
+    fn map(self, f: impl FnOnce(ClientInner) -> ClientInner) -> Self {
+        Self {
+            inner: Arc::new(f(Arc::unwrap_or_clone(self.inner))),
+        }
+    }
+
+    /// The client's API keys, e.g. to check their usage.
+    pub fn api_key_pool(&self) -> &ApiKeyPool {
+        &self.inner.api_keys
+    }
+
+    fn parse_base_url(base_url: &str) -> Result<Url, CompaniesHousePublicDataClientError> {
+        Url::parse(base_url)
+            .ok()
+            .filter(|url| {
+                matches!(url.scheme(), "http" | "https")
+                    && !url.cannot_be_a_base()
+                    && url.query().is_none()
+                    && url.fragment().is_none()
+            })
+            .ok_or_else(|| CompaniesHousePublicDataClientError::BadBaseUrl {
+                base_url: base_url.to_owned(),
+            })
+    }
+
+    /// Send requests to a different host, e.g. a mock server in tests.
+    ///
+    /// The URL must be `http` or `https`, and may include a path prefix.
+    pub fn base_url(self, base_url: &str) -> Result<Self, CompaniesHousePublicDataClientError> {
+        let base_url = Self::parse_base_url(base_url)?;
+        Ok(self.map(|inner| ClientInner { base_url, ..inner }))
+    }
+
+    /// Report response fields which are not modelled by this crate.
+    pub fn report_unknown_fields(self, reporter: UnknownFieldReporter) -> Self {
+        self.map(|inner| ClientInner {
+            unknown_fields: Some(reporter),
+            ..inner
+        })
+    }
+
+    /// Send requests with a different HTTP transport instead of the default
+    /// reqwest one.
+    pub fn transport(self, transport: impl HttpTransport + 'static) -> Self {
+        self.map(|inner| ClientInner {
+            transport: Transport::Custom(Arc::new(transport)),
+            ..inner
+        })
+    }
+
+    /// Send requests with a configured [`reqwest::Client`], e.g. one with a
+    /// proxy or custom TLS roots, instead of a default one.
+    pub fn http_client(self, client: reqwest::Client) -> Self {
+        self.map(|inner| ClientInner {
+            transport: Transport::Reqwest(client.into()),
+            ..inner
+        })
+    }
+
+    /// Wrap the current transport in a [`tower::Layer`], e.g. a timeout or
+    /// concurrency limit. Layers added later wrap those added earlier.
+    #[cfg(feature = "tower")]
+    pub fn layer<L>(self, layer: L) -> Self
+    where
+        L: tower::Layer<DynTransport>,
+        L::Service:
+            tower::Service<HttpRequest, Response = HttpResponse> + Clone + Send + Sync + 'static,
+        <L::Service as tower::Service<HttpRequest>>::Error: Into<tower::BoxError>,
+        <L::Service as tower::Service<HttpRequest>>::Future: Send,
+    {
+        self.map(|inner| {
+            let transport = DynTransport::from(inner.transport.into_shared());
+            ClientInner {
+                transport: Transport::Custom(Arc::new(ServiceTransport::new(
+                    layer.layer(transport),
+                ))),
+                ..inner
+            }
+        })
+    }
+
+    /// Use this client as a [`tower::Service`] of operations, to wrap `send`
+    /// in layers.
+    #[cfg(feature = "tower")]
+    pub fn into_service(self) -> PublicDataService {
+        self.into()
+    }
+
+    /// Rebuild the default reqwest transport, discarding its pooled
+    /// connections. Does nothing when a custom transport is set, and does not
+    /// affect existing clones of this client.
+    pub fn rotate_client(&mut self) -> Result<(), CompaniesHousePublicDataClientError> {
+        if let Transport::Reqwest(transport) = &mut Arc::make_mut(&mut self.inner).transport {
+            *transport = ReqwestTransport::new()?;
+        }
+        Ok(())
+    }
+
+    /// Send an operation and parse its response.
+    ///
+    /// With the `tracing` feature, the request runs in a
+    /// `companies_house.request` span with `operation`, `path`, `status` and
+    /// `duration_ms` fields. There is no retry count, as the client sends each
+    /// operation exactly once; retries added with a [`layer`](Self::layer)
+    /// happen inside the transport, where the span does not see them.
+    pub async fn send<T: CompaniesHousePublicDataOperation>(
+        &self,
+        operation: T,
+    ) -> Result<T::Data, CompaniesHousePublicDataOperationError<T::StatusError>> {
+        let send = self.send_operation(operation);
+        #[cfg(feature = "tracing")]
+        let send = tracing::Instrument::instrument(
+            send,
+            tracing::info_span!(
+                "companies_house.request",
+                operation = unknown_fields::operation_name::<T>(),
+                path = T::PATH_TEMPLATE,
+                status = tracing::field::Empty,
+                duration_ms = tracing::field::Empty,
+            ),
+        );
+        send.await
+    }
+
+    async fn send_operation<T: CompaniesHousePublicDataOperation>(
+        &self,
+        operation: T,
+    ) -> Result<T::Data, CompaniesHousePublicDataOperationError<T::StatusError>> {
+        let request = operation.build_request();
+        let inner = &*self.inner;
+        let url = request.url(&inner.base_url)?;
+        let api_key = inner.api_keys.acquire()?;
+        let mut authorization = HeaderValue::try_from(api_key.provider.api_key().await?)
+            .map_err(|_| ApiKeyError::Invalid)?;
+        authorization.set_sensitive(true);
+        let mut headers = request.headers;
+        headers.insert(header::AUTHORIZATION, authorization);
+
+        #[cfg(any(feature = "tracing", feature = "metrics"))]
+        let start = std::time::Instant::now();
+        let response = inner
+            .transport
+            .get()
+            .execute(HttpRequest {
+                method: request.method,
+                url,
+                headers,
+            })
+            .await;
+        #[cfg(feature = "tracing")]
+        trace_response(&response, start);
+        #[cfg(feature = "metrics")]
+        crate::telemetry::record_response(
+            unknown_fields::operation_name::<T>(),
+            api_key.index,
+            &response,
+            start.elapsed(),
+        );
+        let response = response?;
+
+        match response.status {
+            StatusCode::UNAUTHORIZED => inner.api_keys.quarantine(&api_key),
+            StatusCode::TOO_MANY_REQUESTS => inner.api_keys.exhaust(&api_key),
+            _ => {}
+        }
+
+        operation
+            .handle_status(response.status)
+            .map_err(CompaniesHousePublicDataOperationError::Status)?;
+
+        let bytes = response.body;
+
+        let operation_name = unknown_fields::operation_name::<T>();
+        match unknown_fields::from_slice(&bytes, inner.unknown_fields.as_ref(), operation_name) {
+            Ok(value) => Ok(value),
+            Err(inner) => Err(CompaniesHousePublicDataOperationError::JsonParse {
+                inner,
+                value: serde_json::from_slice(&bytes).ok(),
+                body: bytes,
+            }),
+        }
+    }
+}
+
+/// Record the outcome of a request on the current span.
+#[cfg(feature = "tracing")]
+fn trace_response(response: &Result<HttpResponse, HttpTransportError>, start: std::time::Instant) {
+    let span = tracing::Span::current();
+    let duration_ms = start.elapsed().as_millis() as u64;
+    span.record("duration_ms", duration_ms);
+    match response {
+        Ok(response) => {
+            let status = response.status.as_u16();
+            span.record("status", status);
+            tracing::debug!(status, duration_ms, "Response received");
+        }
+        Err(error) => tracing::warn!(%error, duration_ms, "Request failed"),
+    }
+}
@@ -0,0 +1,3 @@
+//! Types and operations generated from the swagger specs in `specs/`.
+//!
+//! Do not edit by hand, run `cargo xtask codegen` instead.
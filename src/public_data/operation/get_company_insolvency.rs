@@ -0,0 +1,18 @@
+use crate::{
+    macros::operation,
+    types::{CompanyInsolvency, CompanyNumber},
+};
+
+operation! {
+    /// Get the insolvency cases of a company
+    pub struct GetCompanyInsolvency {
+        #[builder(setter(into))]
+        company_number: CompanyNumber,
+    }
+
+    GET ["company", company_number, "insolvency"] => CompanyInsolvency;
+
+    pub enum GetCompanyInsolvencyStatusError {
+        NOT_FOUND => NotFound("Not found"),
+    }
+}
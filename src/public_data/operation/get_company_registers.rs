@@ -0,0 +1,18 @@
+use crate::{
+    macros::operation,
+    types::{CompanyNumber, CompanyRegister},
+};
+
+operation! {
+    /// Get where a company keeps its statutory registers
+    pub struct GetCompanyRegisters {
+        #[builder(setter(into))]
+        company_number: CompanyNumber,
+    }
+
+    GET ["company", company_number, "registers"] => CompanyRegister;
+
+    pub enum GetCompanyRegistersStatusError {
+        NOT_FOUND => NotFound("Not found"),
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{
+    macros::operation,
+    types::{ChargeList, CompanyNumber},
+};
+
+operation! {
+    /// List the charges registered against a company
+    pub struct ListCompanyCharges {
+        #[builder(setter(into))]
+        company_number: CompanyNumber,
+        /// The number of charges to return per page.
+        #[builder(default)]
+        items_per_page: Option<usize>,
+        /// The offset into the entire result set that this page starts.
+        #[builder(default)]
+        start_index: Option<usize>,
+    }
+
+    GET ["company", company_number, "charges"] => ChargeList;
+
+    query {
+        "items_per_page" => items_per_page,
+        "start_index" => start_index,
+    }
+
+    pub enum ListCompanyChargesStatusError {
+        NOT_FOUND => NotFound("Not found"),
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    macros::operation,
+    types::{CompanyNumber, FilingHistoryList},
+};
+
+operation! {
+    /// List the company's filing history
+    pub struct ListCompanyFilingHistory {
+        #[builder(setter(into))]
+        company_number: CompanyNumber,
+        /// One or more comma-separated categories to filter by, e.g.
+        /// `accounts,officers`.
+        #[builder(default)]
+        category: Option<String>,
+        /// The number of filing history items to return per page.
+        #[builder(default)]
+        items_per_page: Option<usize>,
+        /// The offset into the entire result set that this page starts.
+        #[builder(default)]
+        start_index: Option<usize>,
+    }
+
+    GET ["company", company_number, "filing-history"] => FilingHistoryList;
+
+    query {
+        "category" => category,
+        "items_per_page" => items_per_page,
+        "start_index" => start_index,
+    }
+
+    pub enum ListCompanyFilingHistoryStatusError {
+        NOT_FOUND => NotFound("Not found"),
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    macros::operation,
+    types::{CompanyNumber, OfficerList},
+};
+
+operation! {
+    /// List the company officers
+    pub struct ListCompanyOfficers {
+        #[builder(setter(into))]
+        company_number: CompanyNumber,
+        /// The number of officers to return per page.
+        #[builder(default)]
+        items_per_page: Option<usize>,
+        /// The offset into the entire result set that this page starts.
+        #[builder(default)]
+        start_index: Option<usize>,
+        /// The field by which to order the result set, e.g. `appointed_on`,
+        /// `resigned_on` or `surname`. Prefix with `-` to reverse the order.
+        #[builder(default)]
+        order_by: Option<String>,
+    }
+
+    GET ["company", company_number, "officers"] => OfficerList;
+
+    query {
+        "items_per_page" => items_per_page,
+        "start_index" => start_index,
+        "order_by" => order_by,
+    }
+
+    pub enum ListCompanyOfficersStatusError {
+        NOT_FOUND => NotFound("Not found"),
+    }
+}
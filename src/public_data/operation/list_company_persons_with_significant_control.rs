@@ -0,0 +1,35 @@
+use crate::{
+    macros::operation,
+    types::{CompanyNumber, PersonsWithSignificantControlList},
+};
+
+operation! {
+    /// List the company persons with significant control
+    pub struct ListCompanyPersonsWithSignificantControl {
+        #[builder(setter(into))]
+        company_number: CompanyNumber,
+        /// The number of persons with significant control to return per page.
+        #[builder(default)]
+        items_per_page: Option<usize>,
+        /// The offset into the entire result set that this page starts.
+        #[builder(default)]
+        start_index: Option<usize>,
+        /// Display register specific information. If the company has elected to
+        /// hold its register on the public register, only entries held there
+        /// are returned.
+        #[builder(default)]
+        register_view: Option<bool>,
+    }
+
+    GET ["company", company_number, "persons-with-significant-control"] => PersonsWithSignificantControlList;
+
+    query {
+        "items_per_page" => items_per_page,
+        "start_index" => start_index,
+        "register_view" => register_view,
+    }
+
+    pub enum ListCompanyPersonsWithSignificantControlStatusError {
+        NOT_FOUND => NotFound("Not found"),
+    }
+}
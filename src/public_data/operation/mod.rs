@@ -1,21 +1,30 @@
+pub mod get_company_insolvency;
 pub mod get_company_profile;
 pub mod get_company_registered_office_address;
+pub mod get_company_registers;
+pub mod list_company_charges;
+pub mod list_company_filing_history;
+pub mod list_company_officers;
+pub mod list_company_persons_with_significant_control;
 pub mod search_companies;
 pub mod search_disqualified_officers;
 pub mod search_officers;
 
-use reqwest::StatusCode;
+use reqwest::{StatusCode, Url};
 use serde::de::DeserializeOwned;
 use std::fmt::Debug;
 use thiserror::Error;
 
+use crate::{
+    api_key::ApiKeyError,
+    transport::{HttpTransportError, OperationRequest},
+    ApiKeyPoolExhausted,
+};
+
 #[derive(Debug, Error)]
 pub enum CompaniesHousePublicDataOperationError<StatusError: std::error::Error> {
-    #[error("Reqwest error: {inner}")]
-    Reqwest {
-        #[from]
-        inner: reqwest::Error,
-    },
+    #[error("Transport error: {0}")]
+    Transport(#[from] HttpTransportError),
 
     #[error("Unable to parse json body: {inner}")]
     JsonParse {
@@ -26,19 +35,97 @@ pub enum CompaniesHousePublicDataOperationError<StatusError: std::error::Error>
         body: bytes::Bytes,
     },
 
+    #[error(transparent)]
+    EndpointUrl(#[from] EndpointUrlError),
+
+    #[error(transparent)]
+    ApiKeyPoolExhausted(#[from] ApiKeyPoolExhausted),
+
+    #[error("Unable to get API key: {0}")]
+    ApiKey(#[from] ApiKeyError),
+
     #[error(transparent)]
     Status(StatusError),
 }
 
+/// A URL which cannot be built by [`endpoint_url`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum EndpointUrlError {
+    /// An identifier which cannot be used as a URL path segment.
+    #[error("Invalid path segment {segment:?}")]
+    InvalidPathSegment { segment: String },
+    /// A base URL without a path, such as `mailto:` URLs.
+    #[error("Base URL {base_url:?} cannot have path segments")]
+    CannotBeABase { base_url: String },
+}
+
+/// Append `segments` to the client's base URL, percent-encoding each one so
+/// that characters such as `/`, `?` and spaces stay inside their segment.
+///
+/// Empty segments, the relative segments `.` and `..`, and base URLs which
+/// cannot have a path are rejected.
+pub fn endpoint_url(base_url: &Url, segments: &[&str]) -> Result<Url, EndpointUrlError> {
+    if let Some(segment) = segments
+        .iter()
+        .find(|segment| matches!(**segment, "" | "." | ".."))
+    {
+        return Err(EndpointUrlError::InvalidPathSegment {
+            segment: segment.to_string(),
+        });
+    }
+
+    let mut url = base_url.clone();
+    url.path_segments_mut()
+        .map_err(|()| EndpointUrlError::CannotBeABase {
+            base_url: base_url.to_string(),
+        })?
+        .pop_if_empty()
+        .extend(segments);
+    Ok(url)
+}
+
+/// A value which can be sent as a query parameter by an [`operation!`] definition.
+///
+/// [`operation!`]: crate::macros::operation
+pub(crate) trait QueryParam {
+    /// The parameter value, or `None` to leave the parameter out.
+    fn query_value(&self) -> Option<String>;
+}
+
+impl QueryParam for String {
+    fn query_value(&self) -> Option<String> {
+        Some(self.to_owned())
+    }
+}
+
+impl QueryParam for usize {
+    fn query_value(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+impl QueryParam for bool {
+    fn query_value(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+impl<T: QueryParam> QueryParam for Option<T> {
+    fn query_value(&self) -> Option<String> {
+        self.as_ref().and_then(T::query_value)
+    }
+}
+
 pub trait CompaniesHousePublicDataOperation {
     type StatusError: std::error::Error;
     type Data: Debug + DeserializeOwned;
 
-    fn build_request(
-        &self,
-        base_url: &str,
-        client: &reqwest::Client,
-    ) -> Result<reqwest::Request, CompaniesHousePublicDataOperationError<Self::StatusError>>;
+    /// The path with placeholders for identifiers, e.g.
+    /// `/company/{company_number}`, for grouping requests in traces and metrics.
+    const PATH_TEMPLATE: &'static str;
+
+    /// Describe the request, which the client resolves against its base URL.
+    fn build_request(&self) -> OperationRequest;
 
     fn handle_status(&self, response: StatusCode) -> Result<(), Self::StatusError>;
 }
@@ -0,0 +1,138 @@
+//! [`tower`] integration, enabled with the `tower` feature.
+//!
+//! Layers can wrap the HTTP transport, with
+//! [`CompaniesHousePublicDataClient::layer`] or [`ServiceTransport`], or the
+//! whole client, with [`CompaniesHousePublicDataClient::into_service`].
+
+use std::{
+    future::{poll_fn, Future},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower::Service;
+
+use crate::{
+    operation::{CompaniesHousePublicDataOperation, CompaniesHousePublicDataOperationError},
+    transport::{
+        HttpRequest, HttpResponse, HttpTransport, HttpTransportError, HttpTransportFuture,
+        ReqwestTransport,
+    },
+    CompaniesHousePublicDataClient,
+};
+
+impl Service<HttpRequest> for ReqwestTransport {
+    type Response = HttpResponse;
+    type Error = HttpTransportError;
+    type Future = HttpTransportFuture<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: HttpRequest) -> Self::Future {
+        let transport = self.clone();
+        Box::pin(async move { transport.execute(request).await })
+    }
+}
+
+/// Any [`HttpTransport`] as a [`Service`], which is what
+/// [`CompaniesHousePublicDataClient::layer`] wraps in a layer.
+#[derive(Clone)]
+pub struct DynTransport {
+    transport: Arc<dyn HttpTransport>,
+}
+
+impl DynTransport {
+    pub fn new(transport: impl HttpTransport + 'static) -> Self {
+        Self {
+            transport: Arc::new(transport),
+        }
+    }
+}
+
+impl From<Arc<dyn HttpTransport>> for DynTransport {
+    fn from(transport: Arc<dyn HttpTransport>) -> Self {
+        Self { transport }
+    }
+}
+
+impl Service<HttpRequest> for DynTransport {
+    type Response = HttpResponse;
+    type Error = HttpTransportError;
+    type Future = HttpTransportFuture<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: HttpRequest) -> Self::Future {
+        let transport = self.transport.clone();
+        Box::pin(async move { transport.execute(request).await })
+    }
+}
+
+/// An [`HttpTransport`] which sends requests through a [`Service`], e.g. a
+/// [`ReqwestTransport`] wrapped in layers.
+///
+/// The service is cloned for each request, as tower services usually are.
+#[derive(Debug, Clone)]
+pub struct ServiceTransport<S> {
+    service: S,
+}
+
+impl<S> ServiceTransport<S> {
+    pub fn new(service: S) -> Self {
+        Self { service }
+    }
+}
+
+impl<S> HttpTransport for ServiceTransport<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse> + Clone + Send + Sync,
+    S::Error: Into<tower::BoxError>,
+    S::Future: Send,
+{
+    fn execute(&self, request: HttpRequest) -> HttpTransportFuture<'_> {
+        let mut service = self.service.clone();
+        Box::pin(async move {
+            poll_fn(|cx| service.poll_ready(cx))
+                .await
+                .map_err(HttpTransportError::new)?;
+            service.call(request).await.map_err(HttpTransportError::new)
+        })
+    }
+}
+
+/// A [`CompaniesHousePublicDataClient`] as a [`Service`] of operations, created
+/// with [`CompaniesHousePublicDataClient::into_service`].
+#[derive(Clone)]
+pub struct PublicDataService {
+    client: CompaniesHousePublicDataClient,
+}
+
+impl<O> Service<O> for PublicDataService
+where
+    O: CompaniesHousePublicDataOperation + Send + 'static,
+    O::Data: Send,
+    O::StatusError: Send,
+{
+    type Response = O::Data;
+    type Error = CompaniesHousePublicDataOperationError<O::StatusError>;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, operation: O) -> Self::Future {
+        let client = self.client.clone();
+        Box::pin(async move { client.send(operation).await })
+    }
+}
+
+impl From<CompaniesHousePublicDataClient> for PublicDataService {
+    fn from(client: CompaniesHousePublicDataClient) -> Self {
+        Self { client }
+    }
+}
@@ -0,0 +1,119 @@
+//! The HTTP layer used by [`CompaniesHousePublicDataClient`], which can be
+//! replaced with [`CompaniesHousePublicDataClient::transport`], e.g. with a
+//! different HTTP client, recorded responses or an in-memory fake in tests.
+//!
+//! [`CompaniesHousePublicDataClient`]: crate::CompaniesHousePublicDataClient
+//! [`CompaniesHousePublicDataClient::transport`]: crate::CompaniesHousePublicDataClient::transport
+
+use bytes::Bytes;
+use reqwest::{header::HeaderMap, Method, StatusCode, Url};
+use std::{future::Future, pin::Pin};
+use thiserror::Error;
+
+use crate::operation::{endpoint_url, EndpointUrlError};
+
+/// A request described by an operation, independent of any HTTP client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationRequest {
+    pub method: Method,
+    /// Path segments, appended to the client's base URL.
+    pub path: Vec<String>,
+    /// Query parameters, in order.
+    pub query: Vec<(String, String)>,
+    pub headers: HeaderMap,
+}
+
+impl OperationRequest {
+    /// The URL of this request, relative to `base_url`.
+    pub fn url(&self, base_url: &Url) -> Result<Url, EndpointUrlError> {
+        let path: Vec<&str> = self.path.iter().map(String::as_str).collect();
+        let mut url = endpoint_url(base_url, &path)?;
+        if !self.query.is_empty() {
+            url.query_pairs_mut().extend_pairs(&self.query);
+        }
+        Ok(url)
+    }
+}
+
+/// A request ready to send, with the URL resolved against the client's base
+/// URL and the `Authorization` header set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+/// An error from the transport, e.g. a connection failure.
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct HttpTransportError(Box<dyn std::error::Error + Send + Sync>);
+
+impl HttpTransportError {
+    pub fn new(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+        Self(error.into())
+    }
+
+    pub fn into_inner(self) -> Box<dyn std::error::Error + Send + Sync> {
+        self.0
+    }
+}
+
+impl From<reqwest::Error> for HttpTransportError {
+    fn from(error: reqwest::Error) -> Self {
+        Self::new(error)
+    }
+}
+
+pub type HttpTransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<HttpResponse, HttpTransportError>> + Send + 'a>>;
+
+/// Sends [`HttpRequest`]s and reads the whole response body.
+pub trait HttpTransport: Send + Sync {
+    fn execute(&self, request: HttpRequest) -> HttpTransportFuture<'_>;
+}
+
+/// The default transport, backed by a [`reqwest::Client`].
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new() -> Result<Self, reqwest::Error> {
+        Ok(Self {
+            client: reqwest::Client::builder().build()?,
+        })
+    }
+}
+
+impl From<reqwest::Client> for ReqwestTransport {
+    fn from(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn execute(&self, request: HttpRequest) -> HttpTransportFuture<'_> {
+        Box::pin(async move {
+            let response = self
+                .client
+                .request(request.method, request.url)
+                .headers(request.headers)
+                .send()
+                .await?;
+            Ok(HttpResponse {
+                status: response.status(),
+                headers: response.headers().clone(),
+                body: response.bytes().await?,
+            })
+        })
+    }
+}
@@ -0,0 +1,216 @@
+//! https://developer-specs.company-information.service.gov.uk/companies-house-public-data-api/resources/chargelist
+
+use serde::{Deserialize, Serialize};
+
+use super::Date;
+use crate::macros::string_enum;
+
+/// List of charges registered against a company
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChargeList {
+    /// The ETag of the resource.
+    pub etag: Option<String>,
+    /// List of charges.
+    pub items: Vec<ChargeDetails>,
+    /// Number of outstanding charges.
+    pub part_satisfied_count: Option<usize>,
+    /// Number of satisfied charges.
+    pub satisfied_count: Option<usize>,
+    /// Total number of charges returned by the API (filtering applies).
+    pub total_count: Option<usize>,
+    /// Number of charges when no filtering is applied.
+    pub unfiltered_count: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChargeDetails {
+    /// The date the property or undertaking was acquired on.
+    pub acquired_on: Option<Date>,
+    /// Cease/release information about the charge.
+    pub assets_ceased_released: Option<ChargeAssetsCeasedReleased>,
+    /// The charge code is a replacement of the mortgage description.
+    pub charge_code: Option<String>,
+    /// The charge number is used to reference an individual charge.
+    pub charge_number: u32,
+    pub classification: ChargeClassification,
+    /// The date by which the series of debentures were covered.
+    pub covering_instrument_date: Option<Date>,
+    /// The date the charge was created.
+    pub created_on: Option<Date>,
+    /// The date the charge was submitted to Companies House.
+    pub delivered_on: Option<Date>,
+    /// The ETag of the resource.
+    pub etag: Option<String>,
+    /// The id of the charge.
+    pub id: Option<String>,
+    /// Transactions that have been filed for the charge.
+    pub insolvency_cases: Option<Vec<ChargeInsolvencyCase>>,
+    pub links: Option<ChargeLinks>,
+    /// Charge has more than four persons entitled.
+    pub more_than_four_persons_entitled: Option<bool>,
+    pub particulars: Option<ChargeParticulars>,
+    /// People that are entitled to the charge.
+    pub persons_entitled: Option<Vec<ChargePersonEntitled>>,
+    /// The date the issue of the charge was resolved on.
+    pub resolved_on: Option<Date>,
+    /// The date the charge was satisfied.
+    pub satisfied_on: Option<Date>,
+    pub scottish_alterations: Option<ChargeScottishAlterations>,
+    pub secured_details: Option<ChargeSecuredDetails>,
+    pub status: ChargeStatus,
+    /// Transactions that have been filed for the charge.
+    pub transactions: Option<Vec<ChargeTransaction>>,
+}
+
+string_enum! {
+    /// See `assets-ceased-released` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/mortgage_descriptions.yml).
+    pub enum ChargeAssetsCeasedReleased {
+        PropertyCeasedToBelong = "property-ceased-to-belong",
+        PartPropertyReleaseAndCeasedToBelong = "part-property-release-and-ceased-to-belong",
+        PartPropertyReleased = "part-property-released",
+        PartPropertyCeasedToBelong = "part-property-ceased-to-belong",
+        WholePropertyReleased = "whole-property-released",
+        MultipleFilings = "multiple-filings",
+        WholePropertyReleasedAndCeasedToBelong = "whole-property-released-and-ceased-to-belong",
+    }
+}
+
+/// The type of charge and its description.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChargeClassification {
+    /// Details of the charge classification.
+    pub description: String,
+    pub r#type: ChargeClassificationType,
+}
+
+string_enum! {
+    pub enum ChargeClassificationType {
+        ChargeDescription = "charge-description",
+        NatureOfCharge = "nature-of-charge",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChargeInsolvencyCase {
+    /// The number of this insolvency case.
+    pub case_number: Option<String>,
+    pub links: Option<ChargeInsolvencyCaseLinks>,
+    /// The id of the insolvency filing.
+    pub transaction_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChargeInsolvencyCaseLinks {
+    /// Link to the insolvency case related to this charge.
+    pub case: Option<String>,
+}
+
+/// Links to other resources associated with this charge.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChargeLinks {
+    /// Link to this charge resource.
+    #[serde(rename = "self")]
+    pub self_link: String,
+}
+
+/// Details of charge or undertaking.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChargeParticulars {
+    /// The chargor is acting as a bare trustee for the property.
+    pub chargor_acting_as_bare_trustee: Option<bool>,
+    /// The charge contains a fixed charge.
+    pub contains_fixed_charge: Option<bool>,
+    /// The charge contains a floating charge.
+    pub contains_floating_charge: Option<bool>,
+    /// The charge contains a negative pledge.
+    pub contains_negative_pledge: Option<bool>,
+    /// Details of charge particulars.
+    pub description: Option<String>,
+    /// The floating charge covers all the property or undertaking of the company.
+    pub floating_charge_covers_all: Option<bool>,
+    pub r#type: Option<ChargeParticularsType>,
+}
+
+string_enum! {
+    pub enum ChargeParticularsType {
+        ShortParticulars = "short-particulars",
+        ChargedPropertyDescription = "charged-property-description",
+        ChargedPropertyOrUndertakingDescription = "charged-property-or-undertaking-description",
+        BriefDescription = "brief-description",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChargePersonEntitled {
+    /// The name of the person entitled.
+    pub name: String,
+}
+
+/// Information about alterations for Scottish companies.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChargeScottishAlterations {
+    /// The charge has alterations to order.
+    pub has_alterations_to_order: Option<bool>,
+    /// The charge has alterations to prohibitions.
+    pub has_alterations_to_prohibitions: Option<bool>,
+    /// The charge has restricting provisions.
+    pub has_restricting_provisions: Option<bool>,
+}
+
+/// Information about what is secured against this charge.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChargeSecuredDetails {
+    /// Details of the amount or obligation secured by the charge.
+    pub description: String,
+    pub r#type: ChargeSecuredDetailsType,
+}
+
+string_enum! {
+    pub enum ChargeSecuredDetailsType {
+        AmountSecured = "amount-secured",
+        ObligationsSecured = "obligations-secured",
+    }
+}
+
+string_enum! {
+    /// The status of the charge.
+    pub enum ChargeStatus {
+        Outstanding = "outstanding",
+        FullySatisfied = "fully-satisfied",
+        PartSatisfied = "part-satisfied",
+        Satisfied = "satisfied",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChargeTransaction {
+    /// The date the filing was submitted to Companies House.
+    pub delivered_on: Option<Date>,
+    /// Filing type which created, updated or satisfied the charge.
+    pub filing_type: Option<String>,
+    /// The insolvency case related to this filing.
+    pub insolvency_case_number: Option<String>,
+    pub links: Option<ChargeTransactionLinks>,
+    /// The id of the filing.
+    pub transaction_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChargeTransactionLinks {
+    /// Link to the charge filing data.
+    pub filing: Option<String>,
+    /// Link to the insolvency case related to this filing.
+    pub insolvency_case: Option<String>,
+}
@@ -0,0 +1,131 @@
+//! https://developer-specs.company-information.service.gov.uk/companies-house-public-data-api/resources/companyinsolvency
+
+use serde::{Deserialize, Serialize};
+
+use super::Date;
+use crate::macros::string_enum;
+
+/// Insolvency cases of a company
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CompanyInsolvency {
+    /// List of insolvency cases.
+    pub cases: Vec<InsolvencyCase>,
+    /// The ETag of the resource.
+    pub etag: Option<String>,
+    /// Company insolvency status details.
+    pub status: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InsolvencyCase {
+    /// The dates specific to the case.
+    pub dates: Vec<InsolvencyCaseDate>,
+    pub links: Option<InsolvencyCaseLinks>,
+    /// Additional information regarding the case.
+    pub notes: Option<Vec<String>>,
+    /// The case number.
+    pub number: Option<String>,
+    /// The practitioners for the case.
+    pub practitioners: Vec<InsolvencyPractitioner>,
+    pub r#type: InsolvencyCaseType,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InsolvencyCaseDate {
+    /// The case date, described by `type`.
+    pub date: Date,
+    pub r#type: InsolvencyCaseDateType,
+}
+
+string_enum! {
+    /// See `insolvency_case_date_type` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
+    pub enum InsolvencyCaseDateType {
+        InstrumentedOn = "instrumented-on",
+        AdministrationStartedOn = "administration-started-on",
+        AdministrationDischargedOn = "administration-discharged-on",
+        AdministrationEndedOn = "administration-ended-on",
+        ConcludedWindingUpOn = "concluded-winding-up-on",
+        PetitionedOn = "petitioned-on",
+        OrderedToWindUpOn = "ordered-to-wind-up-on",
+        DueToBeDissolvedOn = "due-to-be-dissolved-on",
+        CaseEndOn = "case-end-on",
+        WoundUpOn = "wound-up-on",
+        VoluntaryArrangementStartedOn = "voluntary-arrangement-started-on",
+        VoluntaryArrangementEndedOn = "voluntary-arrangement-ended-on",
+        MoratoriumStartedOn = "moratorium-started-on",
+        MoratoriumEndedOn = "moratorium-ended-on",
+        DeclarationSolventOn = "declaration-solvent-on",
+    }
+}
+
+/// Links to other resources associated with this insolvency case.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InsolvencyCaseLinks {
+    /// Link to the charge.
+    pub charge: Option<String>,
+}
+
+string_enum! {
+    /// See `insolvency_case_type` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
+    pub enum InsolvencyCaseType {
+        CompulsoryLiquidation = "compulsory-liquidation",
+        CreditorsVoluntaryLiquidation = "creditors-voluntary-liquidation",
+        MembersVoluntaryLiquidation = "members-voluntary-liquidation",
+        InAdministration = "in-administration",
+        CorporateVoluntaryArrangement = "corporate-voluntary-arrangement",
+        CorporateVoluntaryArrangementMoratorium = "corporate-voluntary-arrangement-moratorium",
+        AdministrationOrder = "administration-order",
+        ReceiverManager = "receiver-manager",
+        AdministrativeReceiver = "administrative-receiver",
+        ForeignInsolvency = "foreign-insolvency",
+        Moratorium = "moratorium",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InsolvencyPractitioner {
+    pub address: InsolvencyPractitionerAddress,
+    /// The date the practitioner was appointed on.
+    pub appointed_on: Option<Date>,
+    /// The date the practitioner ceased to act for the case.
+    pub ceased_to_act_on: Option<Date>,
+    /// The name of the practitioner.
+    pub name: String,
+    pub role: Option<InsolvencyPractitionerRole>,
+}
+
+/// The practitioner's address.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct InsolvencyPractitionerAddress {
+    /// The first line of the address.
+    pub address_line_1: String,
+    /// The second line of the address.
+    pub address_line_2: Option<String>,
+    /// The country.
+    pub country: Option<String>,
+    /// The locality e.g London.
+    pub locality: String,
+    /// The postal code e.g CF14 3UZ.
+    pub postal_code: Option<String>,
+    /// The region e.g Surrey.
+    pub region: Option<String>,
+}
+
+string_enum! {
+    /// The type of role.
+    pub enum InsolvencyPractitionerRole {
+        FinalLiquidator = "final-liquidator",
+        ReceiverManager = "receiver-manager",
+        ProposedLiquidator = "proposed-liquidator",
+        ProvisionalLiquidator = "provisional-liquidator",
+        AdministrativeReceiver = "administrative-receiver",
+        Practitioner = "practitioner",
+        Receiver = "receiver",
+    }
+}
@@ -0,0 +1,190 @@
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Invalid company number {value:?}")]
+pub struct InvalidCompanyNumber {
+    /// The value which could not be parsed.
+    pub value: String,
+}
+
+/// The register a company number belongs to, as indicated by its prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompanyNumberJurisdiction {
+    EnglandWales,
+    Scotland,
+    NorthernIreland,
+    /// Registered for the whole of the UK, e.g. overseas entities.
+    UnitedKingdom,
+}
+
+/// The kind of entity a company number belongs to, as indicated by its prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompanyNumberKind {
+    Company,
+    AssuranceCompany,
+    CharitableIncorporatedOrganisation,
+    Eeig,
+    EuropeanPublicLimitedLiabilityCompany,
+    FurtherEducationOrSixthFormCollegeCorporation,
+    IndustrialAndProvidentSociety,
+    InvestmentCompanyWithVariableCapital,
+    LimitedLiabilityPartnership,
+    LimitedPartnership,
+    OverseasCompany,
+    OverseasEntity,
+    RegisteredSociety,
+    RoyalCharter,
+    UkEstablishment,
+    UnregisteredCompany,
+}
+
+/// A validated company number, normalised to the 8 character form used by the API.
+///
+/// Parsing uppercases the value and zero-pads the numeric part, so `sc123`
+/// becomes `SC000123` and `1234567` becomes `01234567`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CompanyNumber(String);
+
+impl CompanyNumber {
+    pub fn new(value: &str) -> Result<Self, InvalidCompanyNumber> {
+        normalise(value)
+            .map(Self)
+            .ok_or_else(|| InvalidCompanyNumber {
+                value: value.to_owned(),
+            })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The letters at the start of the number, if any, e.g. `SC`.
+    pub fn prefix(&self) -> Option<&str> {
+        let letters = self.0.bytes().take_while(u8::is_ascii_alphabetic).count();
+        (letters > 0).then(|| &self.0[..letters])
+    }
+
+    /// The register the number belongs to, or `None` for an unrecognised prefix.
+    pub fn jurisdiction(&self) -> Option<CompanyNumberJurisdiction> {
+        self.classify().map(|(jurisdiction, _)| jurisdiction)
+    }
+
+    /// The kind of entity the number belongs to, or `None` for an unrecognised prefix.
+    pub fn kind(&self) -> Option<CompanyNumberKind> {
+        self.classify().map(|(_, kind)| kind)
+    }
+
+    fn classify(&self) -> Option<(CompanyNumberJurisdiction, CompanyNumberKind)> {
+        use CompanyNumberJurisdiction::*;
+        use CompanyNumberKind::*;
+
+        Some(match self.prefix() {
+            None => (EnglandWales, Company),
+            Some(prefix) => match prefix {
+                "AC" => (EnglandWales, AssuranceCompany),
+                "BR" => (EnglandWales, UkEstablishment),
+                "CE" => (EnglandWales, CharitableIncorporatedOrganisation),
+                "FC" => (EnglandWales, OverseasCompany),
+                "FE" => (EnglandWales, FurtherEducationOrSixthFormCollegeCorporation),
+                "GE" => (EnglandWales, Eeig),
+                "IC" => (EnglandWales, InvestmentCompanyWithVariableCapital),
+                "IP" => (EnglandWales, IndustrialAndProvidentSociety),
+                "LP" => (EnglandWales, LimitedPartnership),
+                "OC" => (EnglandWales, LimitedLiabilityPartnership),
+                "RC" => (EnglandWales, RoyalCharter),
+                "RS" => (EnglandWales, RegisteredSociety),
+                "SE" => (EnglandWales, EuropeanPublicLimitedLiabilityCompany),
+                "ZC" => (EnglandWales, UnregisteredCompany),
+                "SA" => (Scotland, AssuranceCompany),
+                "SC" => (Scotland, Company),
+                "CS" => (Scotland, CharitableIncorporatedOrganisation),
+                "SF" => (Scotland, OverseasCompany),
+                "GS" => (Scotland, Eeig),
+                "SI" => (Scotland, InvestmentCompanyWithVariableCapital),
+                "SP" => (Scotland, IndustrialAndProvidentSociety),
+                "SL" => (Scotland, LimitedPartnership),
+                "SO" => (Scotland, LimitedLiabilityPartnership),
+                "SR" => (Scotland, RoyalCharter),
+                "SZ" => (Scotland, UnregisteredCompany),
+                "NA" => (NorthernIreland, AssuranceCompany),
+                "NI" | "R" => (NorthernIreland, Company),
+                "NF" => (NorthernIreland, OverseasCompany),
+                "GN" => (NorthernIreland, Eeig),
+                "NV" => (NorthernIreland, InvestmentCompanyWithVariableCapital),
+                "NP" => (NorthernIreland, IndustrialAndProvidentSociety),
+                "NL" => (NorthernIreland, LimitedPartnership),
+                "NC" => (NorthernIreland, LimitedLiabilityPartnership),
+                "NR" => (NorthernIreland, RoyalCharter),
+                "NZ" => (NorthernIreland, UnregisteredCompany),
+                "OE" => (UnitedKingdom, OverseasEntity),
+                _ => return None,
+            },
+        })
+    }
+}
+
+/// Uppercase and zero-pad to 8 characters, accepting digits with an optional
+/// one or two letter prefix and, for some society registers, a trailing `R`.
+fn normalise(value: &str) -> Option<String> {
+    let value = value.trim().to_ascii_uppercase();
+    let prefix = value.bytes().take_while(u8::is_ascii_alphabetic).count();
+    let (prefix, rest) = value.split_at(prefix);
+    let (digits, suffix) = match rest.strip_suffix('R') {
+        Some(digits) if !prefix.is_empty() => (digits, "R"),
+        _ => (rest, ""),
+    };
+
+    if prefix.len() > 2
+        || digits.is_empty()
+        || !digits.bytes().all(|byte| byte.is_ascii_digit())
+        || prefix.len() + digits.len() + suffix.len() > 8
+    {
+        return None;
+    }
+
+    let width = 8 - prefix.len() - suffix.len();
+    Some(format!("{prefix}{digits:0>width$}{suffix}"))
+}
+
+impl FromStr for CompanyNumber {
+    type Err = InvalidCompanyNumber;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<&str> for CompanyNumber {
+    type Error = InvalidCompanyNumber;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<String> for CompanyNumber {
+    type Error = InvalidCompanyNumber;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(&value)
+    }
+}
+
+impl From<&CompanyNumber> for CompanyNumber {
+    fn from(company_number: &CompanyNumber) -> Self {
+        company_number.clone()
+    }
+}
+
+impl AsRef<str> for CompanyNumber {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for CompanyNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
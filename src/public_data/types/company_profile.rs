@@ -3,10 +3,26 @@
 use serde::{Deserialize, Serialize};
 use serde_aux::prelude::{deserialize_number_from_string, deserialize_option_number_from_string};
 
-use super::{shared::Date, Country, OtherString};
+use super::{
+    shared::{serialize_as_string, serialize_option_as_string},
+    Country, Date,
+};
+use crate::macros::{describe_enum, string_enum};
+#[cfg(feature = "public-data")]
+use crate::{
+    operation::{
+        get_company_insolvency::GetCompanyInsolvency, get_company_profile::GetCompanyProfile,
+        get_company_registers::GetCompanyRegisters, list_company_charges::ListCompanyCharges,
+        list_company_filing_history::ListCompanyFilingHistory,
+        list_company_officers::ListCompanyOfficers,
+        list_company_persons_with_significant_control::ListCompanyPersonsWithSignificantControl,
+    },
+    types::CompanyNumber,
+};
 
 /// Company Profile
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfile {
     /// Company accounts information.
     pub accounts: Option<CompanyProfileAccounts>,
@@ -26,6 +42,8 @@ pub struct CompanyProfile {
     pub date_of_cessation: Option<Date>,
     /// The date when the company was created.
     pub date_of_creation: Option<Date>,
+    /// The ETag of the resource.
+    pub etag: Option<String>,
     /// The number given by an external registration body.
     pub external_registration_number: Option<String>,
     pub foreign_company_details: Option<CompanyProfileForeignCompanyDetails>,
@@ -35,6 +53,8 @@ pub struct CompanyProfile {
     pub has_charges: Option<bool>,
     /// Deprecated. Please use `links.insolvency`.
     pub has_insolvency_history: Option<bool>,
+    /// Flag indicating whether the company has super secure persons with significant control.
+    pub has_super_secure_pscs: Option<bool>,
     /// Deprecated. Please use `subtype`.
     pub is_community_interest_company: Option<bool>,
     pub jurisdiction: Option<CompanyProfileJurisdiction>,
@@ -58,13 +78,14 @@ pub struct CompanyProfile {
 }
 
 /// Company accounts information.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileAccounts {
     /// The Accounting Reference Date (ARD) of the company.
     /// Note: Optional for FC companies
     pub accounting_reference_date: Option<CompanyProfileAccountsAccountingReferenceDate>,
     pub last_accounts: Option<CompanyProfileAccountsLastAccounts>,
-    pub next_account: Option<CompanyProfileAccountsNextAccounts>,
+    pub next_accounts: Option<CompanyProfileAccountsNextAccounts>,
     /// Deprecated. Please use `accounts.next_accounts.due_on`.
     pub next_due: Option<Date>,
     /// Deprecated. Please use `accounts.next_accounts.period_end_on`.
@@ -74,18 +95,26 @@ pub struct CompanyProfileAccounts {
 }
 
 /// The Accounting Reference Date (ARD) of the company.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileAccountsAccountingReferenceDate {
     /// The Accounting Reference Date (ARD) day.
-    #[serde(deserialize_with = "deserialize_number_from_string")]
+    #[serde(
+        deserialize_with = "deserialize_number_from_string",
+        serialize_with = "serialize_as_string"
+    )]
     pub day: u8,
     /// The Accounting Reference Date (ARD) month.
-    #[serde(deserialize_with = "deserialize_number_from_string")]
+    #[serde(
+        deserialize_with = "deserialize_number_from_string",
+        serialize_with = "serialize_as_string"
+    )]
     pub month: u8,
 }
 
 /// The last company accounts filed.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileAccountsLastAccounts {
     /// Deprecated. Please use `period_end_on`.
     pub made_up_to: Option<Date>,
@@ -98,32 +127,35 @@ pub struct CompanyProfileAccountsLastAccounts {
     pub r#type: Option<CompanyProfileAccountsLastAccountsType>,
 }
 
-/// The type of the last company accounts filed.
-/// See `account_type` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum CompanyProfileAccountsLastAccountsType {
-    Null,
-    Full,
-    Small,
-    Medium,
-    Group,
-    Dormant,
-    Interim,
-    Initial,
-    TotalExemptionFull,
-    TotalExemptionSmall,
-    PartialExemption,
-    AuditExemptionSubsidiary,
-    FilingExemptionSubsidiary,
-    MicroEntity,
-    NoAccountsTypeAvailable,
-    AuditedAbridged,
-    UnauditedAbridged,
+string_enum! {
+    /// The type of the last company accounts filed.
+    /// See `account_type` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
+    pub enum CompanyProfileAccountsLastAccountsType {
+        Null = "null",
+        Full = "full",
+        Small = "small",
+        Medium = "medium",
+        Group = "group",
+        Dormant = "dormant",
+        Interim = "interim",
+        Initial = "initial",
+        TotalExemptionFull = "total-exemption-full",
+        TotalExemptionSmall = "total-exemption-small",
+        PartialExemption = "partial-exemption",
+        AuditExemptionSubsidiary = "audit-exemption-subsidiary",
+        FilingExemptionSubsidiary = "filing-exemption-subsidiary",
+        MicroEntity = "micro-entity",
+        NoAccountsTypeAvailable = "no-accounts-type-available",
+        AuditedAbridged = "audited-abridged",
+        UnauditedAbridged = "unaudited-abridged",
+    }
 }
 
+describe_enum!(CompanyProfileAccountsLastAccountsType, "account_type");
+
 /// The next company accounts filed.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileAccountsNextAccounts {
     /// The date the next company accounts are due
     pub due_on: Option<Date>,
@@ -136,7 +168,8 @@ pub struct CompanyProfileAccountsNextAccounts {
 }
 
 /// Annual return information. This member is only returned if a confirmation statement has not be filed.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileAnnualReturn {
     /// The date the last annual return was made up to.
     pub last_made_up_to: Option<Date>,
@@ -149,7 +182,8 @@ pub struct CompanyProfileAnnualReturn {
 }
 
 /// UK branch of a foreign company.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileBranchCompanyDetails {
     /// Type of business undertaken by the UK establishment.
     pub business_activity: Option<String>,
@@ -159,39 +193,44 @@ pub struct CompanyProfileBranchCompanyDetails {
     pub parent_company_number: Option<String>,
 }
 
-/// The status of the company.
-/// See `company_status` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum CompanyProfileCompanyStatus {
-    Active,
-    Dissolved,
-    Liquidation,
-    Receivership,
-    Administration,
-    VoluntaryArrangement,
-    ConvertedClosed,
-    InsolvencyProceedings,
-    Registered,
-    Removed,
-    Closed,
-    Open,
+string_enum! {
+    /// The status of the company.
+    /// See `company_status` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
+    pub enum CompanyProfileCompanyStatus {
+        Active = "active",
+        Dissolved = "dissolved",
+        Liquidation = "liquidation",
+        Receivership = "receivership",
+        Administration = "administration",
+        VoluntaryArrangement = "voluntary-arrangement",
+        ConvertedClosed = "converted-closed",
+        InsolvencyProceedings = "insolvency-proceedings",
+        Registered = "registered",
+        Removed = "removed",
+        Closed = "closed",
+        Open = "open",
+    }
 }
 
-/// Extra details about the status of the company.
-/// See `company_status_detail` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum CompanyProfileCompanyStatusDetail {
-    TransferredFromUk,
-    ActiveProposalToStrikeOff,
-    PetitionToRestoreDissolved,
-    TransformedToSe,
-    ConvertedToPlc,
+describe_enum!(CompanyProfileCompanyStatus, "company_status");
+
+string_enum! {
+    /// Extra details about the status of the company.
+    /// See `company_status_detail` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
+    pub enum CompanyProfileCompanyStatusDetail {
+        TransferredFromUk = "transferred-from-uk",
+        ActiveProposalToStrikeOff = "active-proposal-to-strike-off",
+        PetitionToRestoreDissolved = "petition-to-restore-dissolved",
+        TransformedToSe = "transformed-to-se",
+        ConvertedToPlc = "converted-to-plc",
+    }
 }
 
+describe_enum!(CompanyProfileCompanyStatusDetail, "company_status_detail");
+
 /// Confirmation statement information (N.B. refers to the Annual Statement where type is registered-overseas-entity).
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileConfirmationStatement {
     /// The date to which the company last made a confirmation statement.
     pub last_made_up_to: Option<Date>,
@@ -206,7 +245,8 @@ pub struct CompanyProfileConfirmationStatement {
 }
 
 /// Foreign company details.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileForeignCompanyDetails {
     pub accounting_requirement: Option<CompanyProfileForeignCompanyDetailsAccountingRequirement>,
     pub accounts: Option<CompanyProfileForeignCompanyDetailsAccounts>,
@@ -224,7 +264,8 @@ pub struct CompanyProfileForeignCompanyDetails {
 }
 
 /// Accounts requirement.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileForeignCompanyDetailsAccountingRequirement {
     pub foreign_account_type:
         Option<CompanyProfileForeignCompanyDetailsAccountingRequirementForeignAccountType>,
@@ -232,27 +273,38 @@ pub struct CompanyProfileForeignCompanyDetailsAccountingRequirement {
         Option<CompanyProfileForeignCompanyDetailsAccountingRequirementTermsOfAccountPublication>,
 }
 
-/// Type of accounting requirement that applies.
-/// See `foreign_account_type` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum CompanyProfileForeignCompanyDetailsAccountingRequirementForeignAccountType {
-    AccountingRequirementsOfOriginatingCountryApply,
-    AccountingRequirementsOfOriginatingCountryDoNotApply,
+string_enum! {
+    /// Type of accounting requirement that applies.
+    /// See `foreign_account_type` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
+    pub enum CompanyProfileForeignCompanyDetailsAccountingRequirementForeignAccountType {
+        AccountingRequirementsOfOriginatingCountryApply = "accounting-requirements-of-originating-country-apply",
+        AccountingRequirementsOfOriginatingCountryDoNotApply = "accounting-requirements-of-originating-country-do-not-apply",
+    }
 }
 
-/// Type of accounting requirement that applies.
-/// See `terms_of_account_publication` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum CompanyProfileForeignCompanyDetailsAccountingRequirementTermsOfAccountPublication {
-    AccountsPublicationDateSuppliedByCompany,
-    AccountingPublicationDateDoesNotNeedToBeSuppliedByCompany,
-    AccountingReferenceDateAllocatedByCompaniesHouse,
+describe_enum!(
+    CompanyProfileForeignCompanyDetailsAccountingRequirementForeignAccountType,
+    "foreign_account_type"
+);
+
+string_enum! {
+    /// Type of accounting requirement that applies.
+    /// See `terms_of_account_publication` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
+    pub enum CompanyProfileForeignCompanyDetailsAccountingRequirementTermsOfAccountPublication {
+        AccountsPublicationDateSuppliedByCompany = "accounts-publication-date-supplied-by-company",
+        AccountingPublicationDateDoesNotNeedToBeSuppliedByCompany = "accounting-publication-date-does-not-need-to-be-supplied-by-company",
+        AccountingReferenceDateAllocatedByCompaniesHouse = "accounting-reference-date-allocated-by-companies-house",
+    }
 }
 
+describe_enum!(
+    CompanyProfileForeignCompanyDetailsAccountingRequirementTermsOfAccountPublication,
+    "terms_of_account_publication"
+);
+
 /// Foreign company account information.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileForeignCompanyDetailsAccounts {
     /// Date account period starts under parent law.
     pub account_period_from: Option<CompanyProfileForeignCompanyDetailsAccountsAccountPeriod>,
@@ -261,23 +313,38 @@ pub struct CompanyProfileForeignCompanyDetailsAccounts {
     pub must_file_within: Option<CompanyProfileForeignCompanyDetailsAccountsMustFileWithin>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileForeignCompanyDetailsAccountsAccountPeriod {
-    #[serde(deserialize_with = "deserialize_option_number_from_string")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_number_from_string",
+        serialize_with = "serialize_option_as_string"
+    )]
     pub day: Option<u8>,
-    #[serde(deserialize_with = "deserialize_option_number_from_string")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_number_from_string",
+        serialize_with = "serialize_option_as_string"
+    )]
     pub month: Option<u8>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileForeignCompanyDetailsAccountsMustFileWithin {
     /// Number of months within which to file.
-    #[serde(deserialize_with = "deserialize_option_number_from_string")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_number_from_string",
+        serialize_with = "serialize_option_as_string"
+    )]
     pub months: Option<u8>,
 }
 
 /// Company origin information.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileForeignCompanyDetailsOriginatingRegistry {
     /// Country in which company was incorporated.
     pub country: Option<String>,
@@ -285,40 +352,151 @@ pub struct CompanyProfileForeignCompanyDetailsOriginatingRegistry {
     pub name: Option<String>,
 }
 
-/// The jurisdiction specifies the political body responsible for the company.
-/// See `jurisdiction` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum CompanyProfileJurisdiction {
-    EnglandWales,
-    Wales,
-    Scotland,
-    NorthernIreland,
-    EuropeanUnion,
-    UnitedKingdom,
-    England,
-    #[serde(rename = "noneu")]
-    NonEu,
+string_enum! {
+    /// The jurisdiction specifies the political body responsible for the company.
+    /// See `jurisdiction` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
+    pub enum CompanyProfileJurisdiction {
+        EnglandWales = "england-wales",
+        Wales = "wales",
+        Scotland = "scotland",
+        NorthernIreland = "northern-ireland",
+        EuropeanUnion = "european-union",
+        UnitedKingdom = "united-kingdom",
+        England = "england",
+        NonEu = "noneu",
+    }
 }
 
+describe_enum!(CompanyProfileJurisdiction, "jurisdiction");
+
 /// A set of URLs related to the resource, including self.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct CompanyProfileLinks {}
-
-/// Returned if Companies House is not the primary source of data for this company.
-/// See `partial_data_available` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum CompanyProfilePartialDataAvailable {
-    /// Note: Company starts with: NO
-    FullDataAvailableFromFinancialConductAuthorityMutualsPublicRegister,
-    FullDataAvailableFromFinancialConductAuthority,
-    FullDataAvailableFromDepartmentOfTheEconomy,
-    FullDataAvailableFromTheCompany,
+///
+/// Links are only present for resources which exist for the company, so the
+/// `*_operation` helpers return `None` when there is nothing to fetch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CompanyProfileLinks {
+    /// The URL of the resource.
+    #[serde(rename = "self")]
+    pub self_link: Option<String>,
+    /// The URL of the charges resource for the company.
+    pub charges: Option<String>,
+    /// The URL of the exemptions resource for the company.
+    pub exemptions: Option<String>,
+    /// The URL of the filing history resource for the company.
+    pub filing_history: Option<String>,
+    /// The URL of the insolvency resource for the company.
+    pub insolvency: Option<String>,
+    /// The URL of the officers resource for the company.
+    pub officers: Option<String>,
+    /// The URL of the overseas resource for the company.
+    pub overseas: Option<String>,
+    /// The URL of the persons with significant control resource for the company.
+    pub persons_with_significant_control: Option<String>,
+    /// The URL of the persons with significant control statements resource for the company.
+    pub persons_with_significant_control_statements: Option<String>,
+    /// The URL of the registers resource for the company.
+    pub registers: Option<String>,
+    /// The URL of the UK establishments resource for the company.
+    pub uk_establishments: Option<String>,
 }
 
+#[cfg(feature = "public-data")]
+impl CompanyProfileLinks {
+    /// The company number from a `/company/{company_number}/...` link.
+    fn company_number(link: &str) -> Option<CompanyNumber> {
+        link.strip_prefix("/company/")?
+            .split('/')
+            .next()?
+            .parse()
+            .ok()
+    }
+
+    /// Fetch this company profile again.
+    pub fn self_operation(&self) -> Option<GetCompanyProfile> {
+        Self::company_number(self.self_link.as_deref()?).map(|company_number| {
+            GetCompanyProfile::builder()
+                .company_number(company_number)
+                .build()
+        })
+    }
+
+    /// List the company's officers, if it has any.
+    pub fn officers_operation(&self) -> Option<ListCompanyOfficers> {
+        Self::company_number(self.officers.as_deref()?).map(|company_number| {
+            ListCompanyOfficers::builder()
+                .company_number(company_number)
+                .build()
+        })
+    }
+
+    /// List the company's filing history, if it has any.
+    pub fn filing_history_operation(&self) -> Option<ListCompanyFilingHistory> {
+        Self::company_number(self.filing_history.as_deref()?).map(|company_number| {
+            ListCompanyFilingHistory::builder()
+                .company_number(company_number)
+                .build()
+        })
+    }
+
+    /// List the company's charges, if it has any.
+    pub fn charges_operation(&self) -> Option<ListCompanyCharges> {
+        Self::company_number(self.charges.as_deref()?).map(|company_number| {
+            ListCompanyCharges::builder()
+                .company_number(company_number)
+                .build()
+        })
+    }
+
+    /// List the company's persons with significant control, if it has any.
+    pub fn persons_with_significant_control_operation(
+        &self,
+    ) -> Option<ListCompanyPersonsWithSignificantControl> {
+        Self::company_number(self.persons_with_significant_control.as_deref()?).map(
+            |company_number| {
+                ListCompanyPersonsWithSignificantControl::builder()
+                    .company_number(company_number)
+                    .build()
+            },
+        )
+    }
+
+    /// Get the company's insolvency cases, if it has any.
+    pub fn insolvency_operation(&self) -> Option<GetCompanyInsolvency> {
+        Self::company_number(self.insolvency.as_deref()?).map(|company_number| {
+            GetCompanyInsolvency::builder()
+                .company_number(company_number)
+                .build()
+        })
+    }
+
+    /// Get where the company keeps its registers, if it has moved any.
+    pub fn registers_operation(&self) -> Option<GetCompanyRegisters> {
+        Self::company_number(self.registers.as_deref()?).map(|company_number| {
+            GetCompanyRegisters::builder()
+                .company_number(company_number)
+                .build()
+        })
+    }
+}
+
+string_enum! {
+    /// Returned if Companies House is not the primary source of data for this company.
+    /// See `partial_data_available` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
+    pub enum CompanyProfilePartialDataAvailable {
+        /// Note: Company starts with: NO
+        FullDataAvailableFromFinancialConductAuthorityMutualsPublicRegister = "full-data-available-from-financial-conduct-authority-mutuals-public-register",
+        FullDataAvailableFromFinancialConductAuthority = "full-data-available-from-financial-conduct-authority",
+        FullDataAvailableFromDepartmentOfTheEconomy = "full-data-available-from-department-of-the-economy",
+        FullDataAvailableFromTheCompany = "full-data-available-from-the-company",
+    }
+}
+
+describe_enum!(CompanyProfilePartialDataAvailable, "partial_data_available");
+
 /// A previous name of this company.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfilePreviousCompanyName {
     /// The date on which the company name ceased.
     pub ceased_on: Date,
@@ -329,7 +507,8 @@ pub struct CompanyProfilePreviousCompanyName {
 }
 
 /// The address of the company's registered office.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileRegisteredOfficeAddress {
     /// The first line of the address.
     pub address_line_1: Option<String>,
@@ -338,7 +517,7 @@ pub struct CompanyProfileRegisteredOfficeAddress {
     /// The care of name.
     pub care_of: Option<String>,
     /// The country.
-    pub country: Option<OtherString<Country>>,
+    pub country: Option<Country>,
     /// The locality e.g London.
     pub locality: Option<String>,
     /// The post-office box number.
@@ -352,7 +531,8 @@ pub struct CompanyProfileRegisteredOfficeAddress {
 }
 
 /// The correspondence address of a Registered overseas entity.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanyProfileServiceAddress {
     /// The first line of the address.
     pub address_line_1: Option<String>,
@@ -372,52 +552,55 @@ pub struct CompanyProfileServiceAddress {
     pub region: Option<String>,
 }
 
-/// The subtype of the company.
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum CompanyProfileSubtype {
-    CommunityInterestCompany,
-    PrivateFundLimitedPartnership,
+string_enum! {
+    /// The subtype of the company.
+    pub enum CompanyProfileSubtype {
+        CommunityInterestCompany = "community-interest-company",
+        PrivateFundLimitedPartnership = "private-fund-limited-partnership",
+    }
 }
 
-/// The type of the company.
-/// See `company_type` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum CompanyProfileType {
-    PrivateUnlimited,
-    Ltd,
-    Plc,
-    OldPublicCompany,
-    PrivateLimitedGuarantNscLimitedExemption,
-    LimitedPartnership,
-    PrivateLimitedGuarantNsc,
-    ConvertedOrClosed,
-    PrivateUnlimitedNsc,
-    #[serde(rename = "private-limited-shares-section-30-exemption")]
-    PrivateLimitedSharesSection30Exemption,
-    ProtectedCellCompany,
-    AssuranceCompany,
-    OverseaCompany,
-    Eeig,
-    IcvcSecurities,
-    IcvcWarrant,
-    IcvcUmbrella,
-    RegisteredSocietyNonJurisdictional,
-    IndustrialAndProvidentSociety,
-    NorthernIreland,
-    NorthernIrelandOther,
-    RoyalCharter,
-    InvestmentCompanyWithVariableCapital,
-    UnregisteredCompany,
-    Llp,
-    Other,
-    EuropeanPublicLimitedLiabilityCompanySe,
-    UkEstablishment,
-    ScottishPartnership,
-    CharitableIncorporatedOrganisation,
-    ScottishCharitableIncorporatedOrganisation,
-    FurtherEducationOrSixthFormCollegeCorporation,
-    OverseasEntity,
-    RegisteredOverseasEntity,
+describe_enum!(CompanyProfileSubtype, "company_subtype");
+
+string_enum! {
+    /// The type of the company.
+    /// See `company_type` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
+    pub enum CompanyProfileType {
+        PrivateUnlimited = "private-unlimited",
+        Ltd = "ltd",
+        Plc = "plc",
+        OldPublicCompany = "old-public-company",
+        PrivateLimitedGuarantNscLimitedExemption = "private-limited-guarant-nsc-limited-exemption",
+        LimitedPartnership = "limited-partnership",
+        PrivateLimitedGuarantNsc = "private-limited-guarant-nsc",
+        ConvertedOrClosed = "converted-or-closed",
+        PrivateUnlimitedNsc = "private-unlimited-nsc",
+        PrivateLimitedSharesSection30Exemption = "private-limited-shares-section-30-exemption",
+        ProtectedCellCompany = "protected-cell-company",
+        AssuranceCompany = "assurance-company",
+        OverseaCompany = "oversea-company",
+        Eeig = "eeig",
+        IcvcSecurities = "icvc-securities",
+        IcvcWarrant = "icvc-warrant",
+        IcvcUmbrella = "icvc-umbrella",
+        RegisteredSocietyNonJurisdictional = "registered-society-non-jurisdictional",
+        IndustrialAndProvidentSociety = "industrial-and-provident-society",
+        NorthernIreland = "northern-ireland",
+        NorthernIrelandOther = "northern-ireland-other",
+        RoyalCharter = "royal-charter",
+        InvestmentCompanyWithVariableCapital = "investment-company-with-variable-capital",
+        UnregisteredCompany = "unregistered-company",
+        Llp = "llp",
+        Other = "other",
+        EuropeanPublicLimitedLiabilityCompanySe = "european-public-limited-liability-company-se",
+        UkEstablishment = "uk-establishment",
+        ScottishPartnership = "scottish-partnership",
+        CharitableIncorporatedOrganisation = "charitable-incorporated-organisation",
+        ScottishCharitableIncorporatedOrganisation = "scottish-charitable-incorporated-organisation",
+        FurtherEducationOrSixthFormCollegeCorporation = "further-education-or-sixth-form-college-corporation",
+        OverseasEntity = "overseas-entity",
+        RegisteredOverseasEntity = "registered-overseas-entity",
+    }
 }
+
+describe_enum!(CompanyProfileType, "company_type");
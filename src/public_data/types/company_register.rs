@@ -0,0 +1,81 @@
+//! https://developer-specs.company-information.service.gov.uk/companies-house-public-data-api/resources/companyregister
+
+use serde::{Deserialize, Serialize};
+
+use super::Date;
+use crate::macros::string_enum;
+
+/// Where a company keeps its statutory registers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CompanyRegister {
+    /// The company number of the company.
+    pub company_number: String,
+    /// The ETag of the resource.
+    pub etag: Option<String>,
+    pub kind: String,
+    pub links: CompanyRegisterLinks,
+    pub registers: CompanyRegisters,
+}
+
+/// Links to other resources associated with this registers resource.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CompanyRegisterLinks {
+    /// Link to this registers resource.
+    #[serde(rename = "self")]
+    pub self_link: String,
+}
+
+/// Each register the company keeps, if any have been moved from the
+/// registered office.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CompanyRegisters {
+    pub directors: Option<Register>,
+    pub llp_members: Option<Register>,
+    pub llp_usual_residential_address: Option<Register>,
+    pub members: Option<Register>,
+    pub persons_with_significant_control: Option<Register>,
+    pub secretaries: Option<Register>,
+    pub usual_residential_address: Option<Register>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Register {
+    /// List of register locations, most recent first.
+    pub items: Vec<RegisterItem>,
+    pub links: Option<RegisterLinks>,
+    pub register_type: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RegisterItem {
+    /// The date registered on.
+    pub moved_on: Date,
+    pub register_moved_to: RegisterMovedTo,
+}
+
+string_enum! {
+    /// Location of the register.
+    pub enum RegisterMovedTo {
+        PublicRegister = "public-register",
+        RegisteredOffice = "registered-office",
+        SingleAlternativeInspectionLocation = "single-alternative-inspection-location",
+        UnspecifiedLocation = "unspecified-location",
+    }
+}
+
+/// Links to other resources associated with this register.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RegisterLinks {
+    /// Link to the register's filings or officer list.
+    pub directors_register: Option<String>,
+    pub llp_members_register: Option<String>,
+    pub members_register: Option<String>,
+    pub persons_with_significant_control_register: Option<String>,
+    pub secretaries_register: Option<String>,
+}
@@ -1,20 +1,27 @@
 use serde::{Deserialize, Serialize};
 
-use super::{shared::Date, OtherString};
+use super::Date;
+use crate::macros::{describe_enum, string_enum};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanySearch {
     /// The results of the completed search.
     pub items: Vec<CompanySearchItem>,
     /// The number of search items returned per page.
     pub items_per_page: Option<usize>,
+    /// The type of response returned, e.g. `search#companies`.
+    pub kind: Option<String>,
+    /// The page of results returned.
+    pub page_number: Option<usize>,
     /// The index into the entire result set that this result page starts.
     pub start_index: Option<usize>,
     /// The number of further search results available for the current search.
     pub total_results: Option<usize>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanySearchItem {
     pub address: Option<CompanySearchItemAddress>,
     /// A single line address. This will be the address that matched within the indexed document or the primary address otherwise (as returned by the `address` member).
@@ -31,6 +38,9 @@ pub struct CompanySearchItem {
     pub description: Option<String>,
     /// An array of enumeration types that make up the search description.
     pub description_identifier: Option<Vec<Option<CompanySearchItemDescriptionIdentifier>>>,
+    /// The type of search result.
+    pub kind: Option<String>,
+    pub links: Option<CompanySearchItemLinks>,
     pub matches: Option<CompanySearchItemMatches>,
     /// Summary information for the result showing additional details that have matched.
     pub snippet: Option<String>,
@@ -39,7 +49,8 @@ pub struct CompanySearchItem {
 }
 
 /// The address of the company's registered office.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanySearchItemAddress {
     /// The first line of the address.
     pub address_line_1: Option<String>,
@@ -48,112 +59,122 @@ pub struct CompanySearchItemAddress {
     /// The care of name.
     pub care_of: Option<String>,
     /// The country.
-    pub country: Option<OtherString<CompanySearchItemAddressCountry>>,
+    pub country: Option<CompanySearchItemAddressCountry>,
     /// The locality e.g London.
     pub locality: Option<String>,
     /// The post-office box number.
     pub po_box: Option<String>,
     /// The postal code e.g CF14 3UZ.
     pub postal_code: Option<String>,
+    /// The property name or number.
+    pub premises: Option<String>,
     /// The region e.g Surrey.
     pub region: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub enum CompanySearchItemAddressCountry {
-    Wales,
-    England,
-    Scotland,
-    #[serde(rename = "Great Britain")]
-    GreatBritain,
-    #[serde(rename = "Not specified")]
-    NotSpecified,
-    #[serde(rename = "United Kingdom")]
-    UnitedKingdom,
-    #[serde(rename = "Northern Ireland")]
-    NorthernIreland,
-    #[serde(rename = "Isle Of Man")]
-    IsleOfMan,
-    #[serde(rename = "Virgin Islands, British")]
-    BritishVirginIslands,
+string_enum! {
+    pub enum CompanySearchItemAddressCountry {
+        Wales = "Wales",
+        England = "England",
+        Scotland = "Scotland",
+        GreatBritain = "Great Britain",
+        NotSpecified = "Not specified",
+        UnitedKingdom = "United Kingdom",
+        NorthernIreland = "Northern Ireland",
+        IsleOfMan = "Isle Of Man",
+        BritishVirginIslands = "Virgin Islands, British",
+    }
 }
 
-/// The company status.
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum CompanySearchItemCompanyStatus {
-    Active,
-    Dissolved,
-    Liquidation,
-    Receivership,
-    Administration,
-    VoluntaryArrangement,
-    ConvertedClosed,
-    InsolvencyProceedings,
-    Registered,
-    Removed,
+string_enum! {
+    /// The company status.
+    pub enum CompanySearchItemCompanyStatus {
+        Active = "active",
+        Dissolved = "dissolved",
+        Liquidation = "liquidation",
+        Receivership = "receivership",
+        Administration = "administration",
+        VoluntaryArrangement = "voluntary-arrangement",
+        ConvertedClosed = "converted-closed",
+        InsolvencyProceedings = "insolvency-proceedings",
+        Registered = "registered",
+        Removed = "removed",
+    }
 }
 
-/// The company type.
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum CompanyStatusItemCompanyType {
-    PrivateUnlimited,
-    Ltd,
-    Plc,
-    OldPublicCompany,
-    PrivateLimitedGuarantNscLimitedExemption,
-    LimitedPartnership,
-    PrivateLimitedGuarantNsc,
-    ConvertedOrClosed,
-    PrivateUnlimitedNsc,
-    #[serde(rename = "private-limited-shares-section-30-exemption")]
-    PrivateLimitedSharesSection30Exemption,
-    AssuranceCompany,
-    OverseaCompany,
-    Eeig,
-    IcvcSecurities,
-    IcvcWarrant,
-    IcvcUmbrella,
-    IndustrialAndProvidentSociety,
-    NorthernIreland,
-    NorthernIrelandOther,
-    RoyalCharter,
-    InvestmentCompanyWithVariableCapital,
-    UnregisteredCompany,
-    Llp,
-    Other,
-    EuropeanPublicLimitedLiabilityCompanySe,
-    RegisteredOverseasEntity,
-    /// Almost all company data is blank for this type
-    CharitableIncorporatedOrganisation,
+describe_enum!(CompanySearchItemCompanyStatus, "company_status");
+
+string_enum! {
+    /// The company type.
+    pub enum CompanyStatusItemCompanyType {
+        PrivateUnlimited = "private-unlimited",
+        Ltd = "ltd",
+        Plc = "plc",
+        OldPublicCompany = "old-public-company",
+        PrivateLimitedGuarantNscLimitedExemption = "private-limited-guarant-nsc-limited-exemption",
+        LimitedPartnership = "limited-partnership",
+        PrivateLimitedGuarantNsc = "private-limited-guarant-nsc",
+        ConvertedOrClosed = "converted-or-closed",
+        PrivateUnlimitedNsc = "private-unlimited-nsc",
+        PrivateLimitedSharesSection30Exemption = "private-limited-shares-section-30-exemption",
+        AssuranceCompany = "assurance-company",
+        OverseaCompany = "oversea-company",
+        Eeig = "eeig",
+        IcvcSecurities = "icvc-securities",
+        IcvcWarrant = "icvc-warrant",
+        IcvcUmbrella = "icvc-umbrella",
+        IndustrialAndProvidentSociety = "industrial-and-provident-society",
+        NorthernIreland = "northern-ireland",
+        NorthernIrelandOther = "northern-ireland-other",
+        RoyalCharter = "royal-charter",
+        InvestmentCompanyWithVariableCapital = "investment-company-with-variable-capital",
+        UnregisteredCompany = "unregistered-company",
+        Llp = "llp",
+        Other = "other",
+        EuropeanPublicLimitedLiabilityCompanySe = "european-public-limited-liability-company-se",
+        RegisteredOverseasEntity = "registered-overseas-entity",
+        /// Almost all company data is blank for this type
+        CharitableIncorporatedOrganisation = "charitable-incorporated-organisation",
+    }
+}
+
+describe_enum!(CompanyStatusItemCompanyType, "company_type");
+
+string_enum! {
+    /// The company status.
+    pub enum CompanySearchItemDescriptionIdentifier {
+        IncorporatedOn = "incorporated-on",
+        RegisteredOn = "registered-on",
+        FormedOn = "formed-on",
+        DissolvedOn = "dissolved-on",
+        ConvertedClosedOn = "converted-closed-on",
+        ClosedOn = "closed-on",
+        Closed = "closed",
+        FirstUkEstablishmentOpenedOn = "first-uk-establishment-opened-on",
+        OpenedOn = "opened-on",
+        VoluntaryArrangement = "voluntary-arrangement",
+        Receivership = "receivership",
+        InsolvencyProceedings = "insolvency-proceedings",
+        Liquidation = "liquidation",
+        Administration = "administration",
+        Registered = "registered",
+        Removed = "removed",
+        RegisteredExternally = "registered-externally",
+    }
 }
 
-/// The company status.
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum CompanySearchItemDescriptionIdentifier {
-    IncorporatedOn,
-    RegisteredOn,
-    FormedOn,
-    DissolvedOn,
-    ConvertedClosedOn,
-    ClosedOn,
-    Closed,
-    FirstUkEstablishmentOpenedOn,
-    OpenedOn,
-    VoluntaryArrangement,
-    Receivership,
-    InsolvencyProceedings,
-    Liquidation,
-    Administration,
-    Registered,
-    Removed,
-    RegisteredExternally,
+/// Links to the resource represented by the search result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CompanySearchItemLinks {
+    /// The URL of the search result.
+    #[serde(rename = "self")]
+    pub self_link: Option<String>,
 }
 
 /// A list of members and arrays of character offset defining substrings that matched the search terms.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CompanySearchItemMatches {
     /// An array of character offset into the `address_snippet` string. These always occur in pairs and define the start and end of substrings in the member `address_snippet` that matched the search terms.
     pub address_snippet: Option<Vec<usize>>,
@@ -1,20 +1,27 @@
 use serde::{Deserialize, Serialize};
 
-use super::shared::Date;
+use super::Date;
+use crate::macros::string_enum;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct DisqualifiedOfficerSearch {
     /// The results of the completed search.
     pub items: Option<Vec<DisqualifiedOfficerSearchItem>>,
     /// The number of search items returned per page.
     pub items_per_page: Option<usize>,
+    /// The type of response returned, e.g. `search#disqualified-officers`.
+    pub kind: Option<String>,
+    /// The page of results returned.
+    pub page_number: Option<usize>,
     /// The index into the entire result set that this result page starts.
     pub start_index: Option<usize>,
     /// The number of further search results available for the current search.
     pub total_results: Option<usize>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct DisqualifiedOfficerSearchItem {
     /// The address of the disqualified officer as provided by the disqualifying authority.
     pub address: DisqualifiedOfficerSearchItemAddress,
@@ -26,6 +33,9 @@ pub struct DisqualifiedOfficerSearchItem {
     /// The result description.
     pub description: String,
     pub description_identifiers: Option<Vec<DisqualifiedOfficerSearchItemDescriptionIdentifiers>>,
+    /// The type of search result.
+    pub kind: Option<String>,
+    pub links: Option<DisqualifiedOfficerSearchItemLinks>,
     pub matches: Option<DisqualifiedOfficerSearchItemMatches>,
     /// Summary information for the result showing additional details that have matched.
     pub snippet: Option<String>,
@@ -33,7 +43,8 @@ pub struct DisqualifiedOfficerSearchItem {
     pub title: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct DisqualifiedOfficerSearchItemAddress {
     /// The first line of the address.
     pub address_line_1: Option<String>,
@@ -51,16 +62,26 @@ pub struct DisqualifiedOfficerSearchItemAddress {
     pub region: Option<String>,
 }
 
-/// An array of enumeration types that make up the search description.
-/// See `disqualified_officer_search_description` section in [search descriptions](https://github.com/companieshouse/api-enumerations/blob/master/search_descriptions_raw.yaml).
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum DisqualifiedOfficerSearchItemDescriptionIdentifiers {
-    BornOn,
+string_enum! {
+    /// An array of enumeration types that make up the search description.
+    /// See `disqualified_officer_search_description` section in [search descriptions](https://github.com/companieshouse/api-enumerations/blob/master/search_descriptions_raw.yaml).
+    pub enum DisqualifiedOfficerSearchItemDescriptionIdentifiers {
+        BornOn = "born-on",
+    }
+}
+
+/// Links to the resource represented by the search result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DisqualifiedOfficerSearchItemLinks {
+    /// The URL of the search result.
+    #[serde(rename = "self")]
+    pub self_link: Option<String>,
 }
 
 /// A list of members and arrays of character offset defining substrings that matched the search terms.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct DisqualifiedOfficerSearchItemMatches {
     /// An array of character offset into the `address_snippet` string. These always occur in pairs and define the start and end of substrings in the member `address_snippet` that matched the search terms.
     pub address_snippet: Option<Vec<usize>>,
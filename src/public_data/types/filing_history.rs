@@ -0,0 +1,154 @@
+//! https://developer-specs.company-information.service.gov.uk/companies-house-public-data-api/resources/filinghistorylist
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::Date;
+use crate::{enumerations::format_filing_history_description, macros::string_enum};
+
+/// A page of a company's filing history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct FilingHistoryList {
+    /// The ETag of the resource.
+    pub etag: Option<String>,
+    /// The status of this filing history.
+    pub filing_history_status: Option<FilingHistoryStatus>,
+    /// The filing history items.
+    pub items: Vec<FilingHistory>,
+    /// The number of filing history items returned per page.
+    pub items_per_page: usize,
+    pub kind: Option<String>,
+    /// The index into the entire result set that this result page starts.
+    pub start_index: usize,
+    /// The total number of filing history items for this company.
+    pub total_count: usize,
+}
+
+string_enum! {
+    pub enum FilingHistoryStatus {
+        FilingHistoryAvailable = "filing-history-available",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilingHistory {
+    pub annotations: Option<Vec<FilingHistoryAnnotation>>,
+    pub associated_filings: Option<Vec<FilingHistoryAssociatedFiling>>,
+    /// The barcode of the document.
+    pub barcode: Option<String>,
+    pub category: FilingHistoryCategory,
+    /// The date the filing was processed.
+    pub date: Date,
+    /// A description of the filing.
+    /// For enumeration mappings please see [here](https://github.com/companieshouse/api-enumerations/blob/master/filing_history_descriptions.yml).
+    pub description: String,
+    /// Values to substitute into the `description` template.
+    pub description_values: Option<Map<String, Value>>,
+    pub links: Option<FilingHistoryLinks>,
+    /// Number of pages within the PDF document `links.document_metadata`.
+    pub pages: Option<usize>,
+    /// If true, indicates this is a paper filing.
+    pub paper_filed: Option<bool>,
+    pub resolutions: Option<Vec<FilingHistoryResolution>>,
+    /// The sub-category of the document filed.
+    pub subcategory: Option<FilingHistorySubCategory>,
+    /// The transaction ID of the filing.
+    pub transaction_id: String,
+    /// type
+    pub r#type: String,
+}
+
+impl FilingHistory {
+    /// The description expanded into readable text, if the description key is known.
+    ///
+    /// For example `change-registered-office-address-company-with-date-old-address-new-address`
+    /// becomes `Registered office address changed from ... to ... on 1 January 2024`.
+    pub fn formatted_description(&self) -> Option<String> {
+        format_filing_history_description(&self.description, self.description_values.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilingHistoryAnnotation {
+    /// The annotation text.
+    pub annotation: Option<String>,
+    /// The date the annotation was added.
+    pub date: Date,
+    /// A description of the annotation.
+    /// For enumeration mappings please see [here](https://github.com/companieshouse/api-enumerations/blob/master/filing_history_descriptions.yml).
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilingHistoryAssociatedFiling {
+    /// The date the associated filing was processed.
+    pub date: Date,
+    /// A description of the associated filing.
+    /// For enumeration mappings please see [here](https://github.com/companieshouse/api-enumerations/blob/master/filing_history_descriptions.yml).
+    pub description: String,
+    /// Values to substitute into the `description` template.
+    pub description_values: Option<Map<String, Value>>,
+    pub r#type: String,
+}
+
+impl FilingHistoryAssociatedFiling {
+    /// The description expanded into readable text, if the description key is known.
+    pub fn formatted_description(&self) -> Option<String> {
+        format_filing_history_description(&self.description, self.description_values.as_ref())
+    }
+}
+
+string_enum! {
+    pub enum FilingHistoryCategory {
+        Accounts = "accounts",
+        Address = "address",
+        AnnualReturn = "annual-return",
+        Capital = "capital",
+        ConfirmationStatement = "confirmation-statement",
+        ChangeOfName = "change-of-name",
+        Incorporation = "incorporation",
+        Liquidation = "liquidation",
+        Miscellaneous = "miscellaneous",
+        Mortgage = "mortgage",
+        Officers = "officers",
+        PersonsWithSignificantControl = "persons-with-significant-control",
+        Resolution = "resolution",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilingHistoryLinks {
+    /// Link to this filing history item resource.
+    #[serde(rename = "self")]
+    pub self_link: Option<String>,
+    /// Link to the document metadata associated with this filing history item. See the Document API documentation for more details.
+    pub document_metadata: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilingHistoryResolution {
+    pub category: FilingHistoryResolutionCategory,
+    /// A description of the associated filing.
+    /// /// For enumeration mappings please see [here](https://github.com/companieshouse/api-enumerations/blob/master/filing_history_descriptions.yml).
+    pub description: String,
+    /// The document id of the resolution.
+    pub document_id: Option<String>,
+    /// The date the resolution was processed.
+    pub receive_date: Date,
+    pub subcategory: FilingHistorySubCategory,
+    /// The type of the associated filing.
+    pub r#type: String,
+}
+
+string_enum! {
+    pub enum FilingHistoryResolutionCategory {
+        Miscellaneous = "miscellaneous",
+    }
+}
+
+string_enum! {
+    pub enum FilingHistorySubCategory {
+        Resolution = "resolution",
+    }
+}
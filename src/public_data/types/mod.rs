@@ -1,40 +1,74 @@
+mod charge_list;
+mod company_insolvency;
+mod company_number;
 mod company_profile;
+mod company_register;
 mod company_search;
 mod disqualified_officer_search;
+mod filing_history;
+mod officer_list;
 mod officer_search;
-mod shared;
+mod psc_list;
+pub(crate) mod shared;
 
 use serde::{Deserialize, Serialize};
 
+use crate::macros::string_enum;
+
+pub use charge_list::*;
+pub use company_insolvency::*;
+pub use company_number::{
+    CompanyNumber, CompanyNumberJurisdiction, CompanyNumberKind, InvalidCompanyNumber,
+};
 pub use company_profile::*;
+pub use company_register::*;
 pub use company_search::*;
 pub use disqualified_officer_search::*;
+pub use filing_history::*;
+pub use officer_list::*;
 pub use officer_search::*;
+pub use psc_list::*;
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use shared::{Date, DateParseError, Timestamp};
+
+/// A date, sent by the API as `YYYY-MM-DD`. Enable the `chrono` or `time`
+/// feature to parse it into a typed date.
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+pub type Date = String;
+
+/// A date and time without an offset, sent by the API as
+/// `YYYY-MM-DDTHH:MM:SS` with optional fractional seconds. Enable the `chrono`
+/// or `time` feature to parse it into a typed timestamp.
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+pub type Timestamp = String;
 
-#[derive(Debug, Serialize, Deserialize)]
+/// A known value, or any other string.
+#[deprecated(
+    since = "0.0.6",
+    note = "enums in `types` accept any other string as their `Unknown(String)` variant, use them directly"
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum OtherString<T> {
     Value(T),
     Other(String),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub enum Country {
-    England,
-    Wales,
-    Scotland,
-    #[serde(rename = "Northern Ireland")]
-    NorthernIreland,
-    #[serde(rename = "Great Britain")]
-    GreatBritain,
-    #[serde(rename = "United Kingdom")]
-    UnitedKingdom,
-    #[serde(rename = "Not specified")]
-    NotSpecified,
+string_enum! {
+    pub enum Country {
+        England = "England",
+        Wales = "Wales",
+        Scotland = "Scotland",
+        NorthernIreland = "Northern Ireland",
+        GreatBritain = "Great Britain",
+        UnitedKingdom = "United Kingdom",
+        NotSpecified = "Not specified",
+    }
 }
 
 /// Registered Office Address
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct RegisteredOfficeAddress {
     /// Setting this to true confirms that the new registered office address is an appropriate address as outlined in section 86(2) of the Companies Act 2006.
     pub accept_appropriate_office_address_statement: Option<bool>,
@@ -42,10 +76,18 @@ pub struct RegisteredOfficeAddress {
     pub address_line_1: String,
     /// The second line of the address.
     pub address_line_2: Option<String>,
+    /// The care of name.
+    pub care_of: Option<String>,
     /// The country.
     pub country: Country,
+    /// The ETag of the resource.
+    pub etag: Option<String>,
+    pub kind: Option<String>,
+    pub links: Option<RegisteredOfficeAddressLinks>,
     /// The locality e.g London.
     pub locality: String,
+    /// The post-office box number.
+    pub po_box: Option<String>,
     /// The postal code e.g CF14 3UZ.
     pub postal_code: Option<String>,
     /// The property name or number.
@@ -53,3 +95,12 @@ pub struct RegisteredOfficeAddress {
     /// The region e.g Surrey.
     pub region: Option<String>,
 }
+
+/// Links to other resources associated with the registered office address.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RegisteredOfficeAddressLinks {
+    /// The URL of the resource.
+    #[serde(rename = "self")]
+    pub self_link: Option<String>,
+}
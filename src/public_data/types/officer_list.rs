@@ -0,0 +1,196 @@
+//! https://developer-specs.company-information.service.gov.uk/companies-house-public-data-api/resources/officerlist
+
+use serde::{Deserialize, Serialize};
+
+use super::Date;
+use crate::macros::string_enum;
+
+/// List of company officers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct OfficerList {
+    /// The number of active officers in this result set.
+    pub active_count: Option<usize>,
+    /// The ETag of the resource.
+    pub etag: Option<String>,
+    /// The number of inactive officers in this result set.
+    pub inactive_count: Option<usize>,
+    /// The list of officers.
+    pub items: Vec<OfficerSummary>,
+    /// The number of officers to return per page.
+    pub items_per_page: usize,
+    pub kind: Option<String>,
+    pub links: OfficerListLinks,
+    /// The number of resigned officers in this result set.
+    pub resigned_count: Option<usize>,
+    /// The offset into the entire result set that this page starts.
+    pub start_index: usize,
+    /// The total number of officers in this result set.
+    pub total_results: usize,
+}
+
+/// Links to other resources associated with this officer list resource.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct OfficerListLinks {
+    /// Link to this officer list resource.
+    #[serde(rename = "self")]
+    pub self_link: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct OfficerSummary {
+    pub address: OfficerSummaryAddress,
+    /// The date on which the officer was appointed.
+    pub appointed_on: Option<Date>,
+    /// Only one of appointed_on or appointed_before will be supplied.
+    pub appointed_before: Option<Date>,
+    /// The officer's country of residence.
+    pub country_of_residence: Option<String>,
+    pub date_of_birth: Option<OfficerSummaryDateOfBirth>,
+    /// Former names for the officer.
+    pub former_names: Option<Vec<OfficerSummaryFormerName>>,
+    pub identification: Option<OfficerSummaryIdentification>,
+    /// Indicator representing if the officer was appointed before their appointment date.
+    pub is_pre_1992_appointment: Option<bool>,
+    pub links: OfficerSummaryLinks,
+    /// Corporate or natural officer name.
+    pub name: String,
+    /// The officer's nationality.
+    pub nationality: Option<String>,
+    /// The officer's job title.
+    pub occupation: Option<String>,
+    pub officer_role: OfficerRole,
+    /// The date on which the officer resigned.
+    pub resigned_on: Option<Date>,
+    /// The officer's responsibilities.
+    pub responsibilities: Option<String>,
+}
+
+/// The correspondence address of the officer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct OfficerSummaryAddress {
+    /// The first line of the address.
+    pub address_line_1: Option<String>,
+    /// The second line of the address.
+    pub address_line_2: Option<String>,
+    /// The care of name.
+    pub care_of: Option<String>,
+    /// The country. For example UK.
+    pub country: Option<String>,
+    /// The locality. For example London.
+    pub locality: Option<String>,
+    /// The post-office box number.
+    pub po_box: Option<String>,
+    /// The postal code. For example CF14 3UZ.
+    pub postal_code: Option<String>,
+    /// The property name or number.
+    pub premises: Option<String>,
+    /// The region. For example Surrey.
+    pub region: Option<String>,
+}
+
+/// Details of director date of birth.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct OfficerSummaryDateOfBirth {
+    /// The day of the date of birth.
+    pub day: Option<u8>,
+    /// The month of date of birth.
+    pub month: u8,
+    /// The year of date of birth.
+    pub year: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct OfficerSummaryFormerName {
+    /// Former forenames of the officer.
+    pub forenames: Option<String>,
+    /// Former surnames of the officer.
+    pub surname: Option<String>,
+}
+
+/// Only one from `eea`, `non-eea`, `uk-limited`, `other-corporate-body-or-firm` or
+/// `registered-overseas-entity-corporate-managing-officer` will be supplied.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct OfficerSummaryIdentification {
+    pub identification_type: Option<OfficerSummaryIdentificationType>,
+    /// The legal authority supervising the company.
+    pub legal_authority: Option<String>,
+    /// The legal form of the company as defined by its country of registration.
+    pub legal_form: Option<String>,
+    /// Place registered.
+    pub place_registered: Option<String>,
+    /// Company registration number.
+    pub registration_number: Option<String>,
+}
+
+string_enum! {
+    /// The officer's identity type.
+    pub enum OfficerSummaryIdentificationType {
+        Eea = "eea",
+        NonEea = "non-eea",
+        UkLimitedCompany = "uk-limited-company",
+        OtherCorporateBodyOrFirm = "other-corporate-body-or-firm",
+        RegisteredOverseasEntityCorporateManagingOfficer =
+            "registered-overseas-entity-corporate-managing-officer",
+    }
+}
+
+/// Links to other resources associated with this officer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct OfficerSummaryLinks {
+    pub officer: Option<OfficerSummaryLinksOfficer>,
+    /// Link to this individual company officer appointment resource.
+    #[serde(rename = "self")]
+    pub self_link: String,
+}
+
+/// Links to other officer resources associated with this officer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct OfficerSummaryLinksOfficer {
+    /// Link to the officer's appointment list resource.
+    pub appointments: Option<String>,
+}
+
+string_enum! {
+    /// The officer's role.
+    /// See `officer_role` section in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/constants.yml).
+    pub enum OfficerRole {
+        CicManager = "cic-manager",
+        CorporateDirector = "corporate-director",
+        CorporateLlpDesignatedMember = "corporate-llp-designated-member",
+        CorporateLlpMember = "corporate-llp-member",
+        CorporateManagerOfAnEeig = "corporate-manager-of-an-eeig",
+        CorporateMemberOfAManagementOrgan = "corporate-member-of-a-management-organ",
+        CorporateMemberOfASupervisoryOrgan = "corporate-member-of-a-supervisory-organ",
+        CorporateMemberOfAnAdministrativeOrgan = "corporate-member-of-an-administrative-organ",
+        CorporateNomineeDirector = "corporate-nominee-director",
+        CorporateNomineeSecretary = "corporate-nominee-secretary",
+        CorporateSecretary = "corporate-secretary",
+        Director = "director",
+        GeneralPartnerInALimitedPartnership = "general-partner-in-a-limited-partnership",
+        JudicialFactor = "judicial-factor",
+        LimitedPartnerInALimitedPartnership = "limited-partner-in-a-limited-partnership",
+        LlpDesignatedMember = "llp-designated-member",
+        LlpMember = "llp-member",
+        ManagerOfAnEeig = "manager-of-an-eeig",
+        ManagingOfficer = "managing-officer",
+        MemberOfAManagementOrgan = "member-of-a-management-organ",
+        MemberOfASupervisoryOrgan = "member-of-a-supervisory-organ",
+        MemberOfAnAdministrativeOrgan = "member-of-an-administrative-organ",
+        NomineeDirector = "nominee-director",
+        NomineeSecretary = "nominee-secretary",
+        PersonAuthorisedToAccept = "person-authorised-to-accept",
+        PersonAuthorisedToRepresent = "person-authorised-to-represent",
+        PersonAuthorisedToRepresentAndAccept = "person-authorised-to-represent-and-accept",
+        ReceiverAndManager = "receiver-and-manager",
+        Secretary = "secretary",
+    }
+}
@@ -1,18 +1,26 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+use crate::macros::string_enum;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct OfficerSearch {
     /// The results of the completed search.
     pub items: Option<Vec<OfficerSearchItem>>,
     /// The number of search items returned per page.
     pub items_per_page: Option<usize>,
+    /// The type of response returned, e.g. `search#officers`.
+    pub kind: Option<String>,
+    /// The page of results returned.
+    pub page_number: Option<usize>,
     /// The index into the entire result set that this result page starts.
     pub start_index: Option<usize>,
     /// The number of further search results available for the current search.
     pub total_results: Option<usize>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct OfficerSearchItem {
     pub address: OfficerSearchItemAddress,
     /// A single line address. This will be the address that matched within the indexed
@@ -24,6 +32,9 @@ pub struct OfficerSearchItem {
     /// The result description.
     pub description: String,
     pub description_identifiers: Option<Vec<OfficerSearchItemDescriptionIdentifiers>>,
+    /// The type of search result.
+    pub kind: Option<String>,
+    pub links: Option<OfficerSearchItemLinks>,
     pub matches: Option<OfficerSearchItemMatches>,
     /// Summary information for the result showing additional details that have matched.
     pub snippet: Option<String>,
@@ -31,7 +42,8 @@ pub struct OfficerSearchItem {
     pub title: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct OfficerSearchItemAddress {
     /// The first line of the address.
     pub address_line_1: Option<String>,
@@ -54,7 +66,8 @@ pub struct OfficerSearchItemAddress {
 }
 
 /// The officer date of birth details.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct OfficerSearchItemDateOfBirth {
     /// The month the officer was born in.
     pub month: u8,
@@ -62,17 +75,27 @@ pub struct OfficerSearchItemDateOfBirth {
     pub year: u16,
 }
 
-/// An array of enumeration types that make up the search description.
-/// See `officer_search_description` section in [search descriptions](https://github.com/companieshouse/api-enumerations/blob/master/search_descriptions_raw.yaml).
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum OfficerSearchItemDescriptionIdentifiers {
-    AppointmentCount,
-    BornOn,
+string_enum! {
+    /// An array of enumeration types that make up the search description.
+    /// See `officer_search_description` section in [search descriptions](https://github.com/companieshouse/api-enumerations/blob/master/search_descriptions_raw.yaml).
+    pub enum OfficerSearchItemDescriptionIdentifiers {
+        AppointmentCount = "appointment-count",
+        BornOn = "born-on",
+    }
+}
+
+/// Links to the resource represented by the search result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct OfficerSearchItemLinks {
+    /// The URL of the search result.
+    #[serde(rename = "self")]
+    pub self_link: Option<String>,
 }
 
 /// A list of members and arrays of character offset defining substrings that matched the search terms.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct OfficerSearchItemMatches {
     /// An array of character offset into the `address_snippet` string. These always occur in pairs and define the start and end of substrings in the member `address_snippet` that matched the search terms.
     pub address_snippet: Option<Vec<usize>>,
@@ -0,0 +1,159 @@
+//! https://developer-specs.company-information.service.gov.uk/companies-house-public-data-api/resources/list
+
+use serde::{Deserialize, Serialize};
+
+use super::Date;
+use crate::macros::string_enum;
+
+/// List of persons with significant control
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PersonsWithSignificantControlList {
+    /// The number of active persons with significant control in this result set.
+    pub active_count: Option<usize>,
+    /// The number of ceased persons with significant control in this result set.
+    pub ceased_count: Option<usize>,
+    /// The ETag of the resource.
+    pub etag: Option<String>,
+    /// The list of persons with significant control.
+    pub items: Vec<PersonWithSignificantControl>,
+    /// The number of persons with significant control to return per page.
+    pub items_per_page: usize,
+    pub links: PersonsWithSignificantControlListLinks,
+    /// The offset into the entire result set that this page starts.
+    pub start_index: usize,
+    /// The total number of persons with significant control in this result set.
+    pub total_results: usize,
+}
+
+/// Links to other resources associated with this list resource.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PersonsWithSignificantControlListLinks {
+    /// The URL of the resource.
+    #[serde(rename = "self")]
+    pub self_link: String,
+    /// The URL of the statements resource for this company.
+    pub persons_with_significant_control_statements: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PersonWithSignificantControl {
+    pub address: Option<PersonWithSignificantControlAddress>,
+    /// Presence of this flag indicates that the person with significant control has ceased.
+    pub ceased: Option<bool>,
+    /// The date that the person with significant control ceased.
+    pub ceased_on: Option<Date>,
+    /// The country of residence of the person with significant control.
+    pub country_of_residence: Option<String>,
+    pub date_of_birth: Option<PersonWithSignificantControlDateOfBirth>,
+    /// Description of the super secure person with significant control.
+    pub description: Option<String>,
+    /// The ETag of the resource.
+    pub etag: Option<String>,
+    pub identification: Option<PersonWithSignificantControlIdentification>,
+    pub kind: PersonWithSignificantControlKind,
+    pub links: PersonWithSignificantControlLinks,
+    /// Name of the person with significant control.
+    pub name: Option<String>,
+    pub name_elements: Option<PersonWithSignificantControlNameElements>,
+    /// The nationality of the person with significant control.
+    pub nationality: Option<String>,
+    /// Indicates the nature of control the person with significant control holds.
+    ///
+    /// See `short_description` in [enumeration mappings](https://github.com/companieshouse/api-enumerations/blob/master/psc_descriptions.yml).
+    pub natures_of_control: Option<Vec<String>>,
+    /// The date that Companies House was notified about this person with significant control.
+    pub notified_on: Option<Date>,
+}
+
+/// The address of the person with significant control.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PersonWithSignificantControlAddress {
+    /// The first line of the address.
+    pub address_line_1: Option<String>,
+    /// The second line of the address.
+    pub address_line_2: Option<String>,
+    /// Care of name.
+    pub care_of: Option<String>,
+    /// The country e.g. United Kingdom.
+    pub country: Option<String>,
+    /// The locality e.g London.
+    pub locality: Option<String>,
+    /// The post-officer box number.
+    pub po_box: Option<String>,
+    /// The postal code e.g CF14 3UZ.
+    pub postal_code: Option<String>,
+    /// The property name or number.
+    pub premises: Option<String>,
+    /// The region e.g Surrey.
+    pub region: Option<String>,
+}
+
+/// Date of birth of the person with significant control.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PersonWithSignificantControlDateOfBirth {
+    /// The day of the date of birth.
+    pub day: Option<u8>,
+    /// The month of the date of birth.
+    pub month: u8,
+    /// The year of the date of birth.
+    pub year: u16,
+}
+
+/// Identification of a corporate or legal person with significant control.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PersonWithSignificantControlIdentification {
+    /// The country or state the legal entity is registered in.
+    pub country_registered: Option<String>,
+    /// The legal authority supervising the legal entity.
+    pub legal_authority: Option<String>,
+    /// The legal form of the legal entity as defined by its country of registration.
+    pub legal_form: Option<String>,
+    /// The place the legal entity is registered.
+    pub place_registered: Option<String>,
+    /// The registration number of the legal entity.
+    pub registration_number: Option<String>,
+}
+
+string_enum! {
+    pub enum PersonWithSignificantControlKind {
+        IndividualPersonWithSignificantControl = "individual-person-with-significant-control",
+        CorporateEntityPersonWithSignificantControl = "corporate-entity-person-with-significant-control",
+        LegalPersonPersonWithSignificantControl = "legal-person-person-with-significant-control",
+        SuperSecurePersonWithSignificantControl = "super-secure-person-with-significant-control",
+        IndividualBeneficialOwner = "individual-beneficial-owner",
+        CorporateEntityBeneficialOwner = "corporate-entity-beneficial-owner",
+        LegalPersonBeneficialOwner = "legal-person-beneficial-owner",
+        SuperSecureBeneficialOwner = "super-secure-beneficial-owner",
+    }
+}
+
+/// Links to other resources associated with this person with significant control.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PersonWithSignificantControlLinks {
+    /// The URL of the resource.
+    #[serde(rename = "self")]
+    pub self_link: String,
+    /// The URL of the statement linked to this person with significant control.
+    pub statement: Option<String>,
+}
+
+/// Name components of the person with significant control.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PersonWithSignificantControlNameElements {
+    /// The forename of the person with significant control.
+    pub forename: Option<String>,
+    /// Other forenames of the person with significant control.
+    pub other_forenames: Option<String>,
+    /// The surname of the person with significant control.
+    pub surname: Option<String>,
+    /// Title of the person with significant control.
+    pub title: Option<String>,
+}
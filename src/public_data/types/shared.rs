@@ -1 +1,347 @@
-pub type Date = String;
+//! The typed [`Date`] and [`Timestamp`] are public with the `chrono` or `time`
+//! features, and otherwise only used inside the crate, e.g. to measure stream
+//! lag, while fields keep the API's strings.
+#![cfg_attr(not(any(feature = "chrono", feature = "time")), allow(dead_code))]
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Invalid ISO-8601 {expected}: {value:?}")]
+pub struct DateParseError {
+    /// The value which could not be parsed.
+    pub value: String,
+    /// Description of the expected format.
+    pub expected: &'static str,
+}
+
+/// A calendar date, sent by the API as `YYYY-MM-DD`.
+///
+/// Convert to `chrono::NaiveDate` or `time::Date` with the `chrono` or `time`
+/// features enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Returns `None` if the day does not exist in the given month.
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if year > 9999 || !(1..=12).contains(&month) || day == 0 {
+            return None;
+        }
+        let days_in_month = match month {
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        };
+        (day <= days_in_month).then_some(Self { year, month, day })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Days since 1970-01-01, see <http://howardhinnant.github.io/date_algorithms.html>.
+    fn days_since_unix_epoch(&self) -> i64 {
+        let (month, day) = (self.month as i64, self.day as i64);
+        let year = self.year as i64 - if month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+}
+
+fn parse_digits<T: FromStr>(value: &str, range: std::ops::Range<usize>) -> Option<T> {
+    let digits = value.get(range)?;
+    if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+fn parse_date(value: &str) -> Option<Date> {
+    let bytes = value.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    Date::new(
+        parse_digits(value, 0..4)?,
+        parse_digits(value, 5..7)?,
+        parse_digits(value, 8..10)?,
+    )
+}
+
+impl FromStr for Date {
+    type Err = DateParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        parse_date(value).ok_or_else(|| DateParseError {
+            value: value.to_owned(),
+            expected: "date (YYYY-MM-DD)",
+        })
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl Serialize for Date {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serialize a number as a string, for fields the API sends as numeric
+/// strings, so they are written back in the same form.
+pub(crate) fn serialize_as_string<T: fmt::Display, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+/// [`serialize_as_string`] for optional fields.
+pub(crate) fn serialize_option_as_string<T: fmt::Display, S: Serializer>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serializer.collect_str(value),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Parse a date field, whichever type it has with the enabled features.
+pub(crate) trait AsDate {
+    fn as_date(&self) -> Option<Date>;
+}
+
+impl AsDate for String {
+    fn as_date(&self) -> Option<Date> {
+        parse_date(self)
+    }
+}
+
+impl AsDate for Date {
+    fn as_date(&self) -> Option<Date> {
+        Some(*self)
+    }
+}
+
+/// Parse a timestamp field, whichever type it has with the enabled features.
+#[cfg(feature = "streaming")]
+pub(crate) trait AsTimestamp {
+    fn as_timestamp(&self) -> Option<Timestamp>;
+}
+
+#[cfg(feature = "streaming")]
+impl AsTimestamp for String {
+    fn as_timestamp(&self) -> Option<Timestamp> {
+        parse_timestamp(self)
+    }
+}
+
+#[cfg(feature = "streaming")]
+impl AsTimestamp for Timestamp {
+    fn as_timestamp(&self) -> Option<Timestamp> {
+        Some(*self)
+    }
+}
+
+/// A date and time without an offset, sent by the API as
+/// `YYYY-MM-DDTHH:MM:SS` with optional fractional seconds.
+///
+/// Convert to `chrono::NaiveDateTime` or `time::PrimitiveDateTime` with the
+/// `chrono` or `time` features enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Timestamp {
+    date: Date,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+impl Timestamp {
+    /// Returns `None` if the time of day is out of range.
+    pub fn new(date: Date, hour: u8, minute: u8, second: u8, nanosecond: u32) -> Option<Self> {
+        (hour < 24 && minute < 60 && second < 60 && nanosecond < 1_000_000_000).then_some(Self {
+            date,
+            hour,
+            minute,
+            second,
+            nanosecond,
+        })
+    }
+
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// Whole seconds since 1970-01-01T00:00:00, treating the timestamp as UTC.
+    pub fn unix_seconds(&self) -> i64 {
+        self.date.days_since_unix_epoch() * 86400
+            + self.hour as i64 * 3600
+            + self.minute as i64 * 60
+            + self.second as i64
+    }
+}
+
+fn parse_timestamp(value: &str) -> Option<Timestamp> {
+    let (date, time) = value.split_once('T')?;
+    let (time, fraction) = match time.split_once('.') {
+        Some((time, fraction)) => (time, Some(fraction)),
+        None => (time, None),
+    };
+    let bytes = time.as_bytes();
+    if bytes.len() != 8 || bytes[2] != b':' || bytes[5] != b':' {
+        return None;
+    }
+    let nanosecond = match fraction {
+        None => 0,
+        Some(fraction) if (1..=9).contains(&fraction.len()) => {
+            parse_digits::<u32>(fraction, 0..fraction.len())? * 10u32.pow(9 - fraction.len() as u32)
+        }
+        Some(_) => return None,
+    };
+    Timestamp::new(
+        parse_date(date)?,
+        parse_digits(time, 0..2)?,
+        parse_digits(time, 3..5)?,
+        parse_digits(time, 6..8)?,
+        nanosecond,
+    )
+}
+
+impl FromStr for Timestamp {
+    type Err = DateParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        parse_timestamp(value).ok_or_else(|| DateParseError {
+            value: value.to_owned(),
+            expected: "timestamp (YYYY-MM-DDTHH:MM:SS)",
+        })
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}T{:02}:{:02}:{:02}",
+            self.date, self.hour, self.minute, self.second
+        )?;
+        if self.nanosecond != 0 {
+            let fraction = format!("{:09}", self.nanosecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use super::{Date, Timestamp};
+
+    impl From<Date> for chrono::NaiveDate {
+        fn from(date: Date) -> Self {
+            chrono::NaiveDate::from_ymd_opt(date.year as i32, date.month as u32, date.day as u32)
+                .expect("date components are validated on construction")
+        }
+    }
+
+    impl From<Timestamp> for chrono::NaiveDateTime {
+        fn from(timestamp: Timestamp) -> Self {
+            chrono::NaiveDate::from(timestamp.date)
+                .and_hms_nano_opt(
+                    timestamp.hour as u32,
+                    timestamp.minute as u32,
+                    timestamp.second as u32,
+                    timestamp.nanosecond,
+                )
+                .expect("time components are validated on construction")
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use super::{Date, Timestamp};
+
+    impl From<Date> for time::Date {
+        fn from(date: Date) -> Self {
+            time::Month::try_from(date.month)
+                .and_then(|month| time::Date::from_calendar_date(date.year as i32, month, date.day))
+                .expect("date components are validated on construction")
+        }
+    }
+
+    impl From<Timestamp> for time::PrimitiveDateTime {
+        fn from(timestamp: Timestamp) -> Self {
+            let time = time::Time::from_hms_nano(
+                timestamp.hour,
+                timestamp.minute,
+                timestamp.second,
+                timestamp.nanosecond,
+            )
+            .expect("time components are validated on construction");
+            time::PrimitiveDateTime::new(timestamp.date.into(), time)
+        }
+    }
+}
@@ -1,62 +1,159 @@
 use super::{
     operation::CompaniesHouseStreamingOperation, CompaniesHouseStreamingConnectionError,
-    StreamConnection,
+    LineFramer, StreamConnection, DEFAULT_MAX_LINE_LENGTH,
+};
+use crate::{
+    api_key::{ApiKeyProvider, StaticApiKey},
+    UnknownFieldReporter,
 };
-use bytes::BytesMut;
 use reqwest::StatusCode;
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 use tokio::time::timeout;
 
 const STREAMING_BASE_URL: &str = "https://stream.companieshouse.gov.uk";
 
+/// A client for the Companies House streaming API.
+///
+/// The client is `Send + Sync` and cheap to clone, as clones share its
+/// [`reqwest::Client`] and its connection pool through an internal `Arc`.
+#[derive(Clone)]
 pub struct CompaniesHouseStreamingClient {
-    api_key: String,
+    inner: Arc<ClientInner>,
+}
+
+#[derive(Clone)]
+struct ClientInner {
+    http_client: reqwest::Client,
+    api_key: Arc<dyn ApiKeyProvider>,
+    base_url: String,
     connection_timeout: Duration,
     chunk_timeout: Duration,
+    max_line_length: usize,
+    unknown_fields: Option<UnknownFieldReporter>,
 }
 
 impl CompaniesHouseStreamingClient {
     pub fn new(api_key: &str) -> Self {
+        Self::with_api_key_provider(StaticApiKey::new(api_key))
+    }
+
+    /// Ask `provider` for the API key for every connection, so the key can
+    /// change at runtime.
+    pub fn with_api_key_provider(provider: impl ApiKeyProvider + 'static) -> Self {
         Self {
-            api_key: api_key.to_owned(),
-            connection_timeout: Duration::from_secs(60),
-            chunk_timeout: Duration::from_secs(60),
+            inner: Arc::new(ClientInner {
+                http_client: reqwest::Client::new(),
+                api_key: Arc::new(provider),
+                base_url: STREAMING_BASE_URL.to_owned(),
+                connection_timeout: Duration::from_secs(60),
+                chunk_timeout: Duration::from_secs(60),
+                max_line_length: DEFAULT_MAX_LINE_LENGTH,
+                unknown_fields: None,
+            }),
         }
     }
 
-    pub fn connection_timeout(self, duration: Duration) -> Self {
+    fn map(self, f: impl FnOnce(ClientInner) -> ClientInner) -> Self {
         Self {
-            connection_timeout: duration,
-            ..self
+            inner: Arc::new(f(Arc::unwrap_or_clone(self.inner))),
         }
     }
 
+    /// Connect with a configured [`reqwest::Client`], e.g. one with a proxy or
+    /// custom TLS roots. It should not have an overall request timeout, which
+    /// would end long-lived streams; use [`chunk_timeout`](Self::chunk_timeout)
+    /// instead.
+    pub fn http_client(self, http_client: reqwest::Client) -> Self {
+        self.map(|inner| ClientInner {
+            http_client,
+            ..inner
+        })
+    }
+
+    /// Connect to a different host, e.g. a mock server in tests.
+    pub fn base_url(self, base_url: impl Into<String>) -> Self {
+        self.map(|inner| ClientInner {
+            base_url: base_url.into(),
+            ..inner
+        })
+    }
+
+    pub fn connection_timeout(self, duration: Duration) -> Self {
+        self.map(|inner| ClientInner {
+            connection_timeout: duration,
+            ..inner
+        })
+    }
+
     pub fn chunk_timeout(self, duration: Duration) -> Self {
-        Self {
+        self.map(|inner| ClientInner {
             chunk_timeout: duration,
-            ..self
-        }
+            ..inner
+        })
+    }
+
+    /// Maximum size in bytes of a single stream item before the connection
+    /// reports [`CompaniesHouseStreamingNextError::LineTooLong`](super::CompaniesHouseStreamingNextError::LineTooLong).
+    pub fn max_line_length(self, max_line_length: usize) -> Self {
+        self.map(|inner| ClientInner {
+            max_line_length,
+            ..inner
+        })
+    }
+
+    /// Report fields in stream items which are not modelled by this crate.
+    pub fn report_unknown_fields(self, reporter: UnknownFieldReporter) -> Self {
+        self.map(|inner| ClientInner {
+            unknown_fields: Some(reporter),
+            ..inner
+        })
     }
 
     pub async fn stream<O: CompaniesHouseStreamingOperation>(
         &self,
         operation: O,
         timepoint: Option<usize>,
+    ) -> Result<StreamConnection<O>, CompaniesHouseStreamingConnectionError> {
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "companies_house.stream",
+            endpoint = operation.endpoint(),
+            start_timepoint = timepoint,
+            timepoint = tracing::field::Empty,
+            status = tracing::field::Empty,
+        );
+        let connect = self.connect(operation, timepoint);
+        #[cfg(feature = "tracing")]
+        let connect = tracing::Instrument::instrument(connect, span);
+        connect.await
+    }
+
+    async fn connect<O: CompaniesHouseStreamingOperation>(
+        &self,
+        operation: O,
+        timepoint: Option<usize>,
     ) -> Result<StreamConnection<O>, CompaniesHouseStreamingConnectionError> {
         match &timepoint {
             Some(t) => log::info!("Connecting to stream with timepoint {t}"),
             None => log::info!("Connecting to stream without timepoint..."),
         };
 
-        let mut request = reqwest::Client::new()
-            .get(format!("{}{}", STREAMING_BASE_URL, operation.endpoint()))
-            .basic_auth(&self.api_key, Option::<&str>::None);
+        let inner = &*self.inner;
+        let api_key = inner
+            .api_key
+            .api_key()
+            .await
+            .map_err(CompaniesHouseStreamingConnectionError::ApiKey)?;
+        let mut request = inner
+            .http_client
+            .get(format!("{}{}", inner.base_url, operation.endpoint()))
+            .basic_auth(api_key, Option::<&str>::None);
 
         if let Some(timepoint) = timepoint {
             request = request.query(&[("timepoint", timepoint)]);
         }
 
-        let response = timeout(self.connection_timeout, request.send())
+        let response = timeout(inner.connection_timeout, request.send())
             .await
             .map_err(|_| {
                 log::info!("Connection timeout");
@@ -65,12 +162,18 @@ impl CompaniesHouseStreamingClient {
             .map_err(CompaniesHouseStreamingConnectionError::UnknownConnection)?;
 
         log::info!(status = response.status().as_u16(); "Connection successful");
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("status", response.status().as_u16());
 
         match response.status() {
             StatusCode::OK => Ok(StreamConnection {
-                buffer: BytesMut::new(),
-                max_chunk_timeout: self.chunk_timeout,
+                framer: LineFramer::new(inner.max_line_length),
+                max_chunk_timeout: inner.chunk_timeout,
                 response,
+                recorder: None,
+                unknown_fields: inner.unknown_fields.clone(),
+                #[cfg(feature = "tracing")]
+                span: tracing::Span::current(),
                 _operation: operation,
             }),
             StatusCode::RANGE_NOT_SATISFIABLE => {
@@ -2,6 +2,7 @@ use reqwest::StatusCode;
 use thiserror::Error;
 
 use super::operation::StreamItem;
+use crate::api_key::ApiKeyError;
 
 #[derive(Debug, Error)]
 pub enum CompaniesHouseStreamingConnectionError {
@@ -17,6 +18,8 @@ pub enum CompaniesHouseStreamingConnectionError {
     BadTimepoint,
     #[error("Unknown connection response {0}")]
     UnknownResponse(StatusCode),
+    #[error("Unable to get API key")]
+    ApiKey(#[source] ApiKeyError),
 }
 
 #[derive(Debug, Error)]
@@ -27,6 +30,12 @@ pub enum CompaniesHouseStreamingNextError {
     StreamComplete,
     #[error("Unable to read from response body")]
     BadChunk(reqwest::Error),
+    #[error("Stream item exceeds the maximum line length of {max_line_length} bytes")]
+    LineTooLong { max_line_length: usize },
+    #[error("Unable to record stream item")]
+    RecordItem(std::io::Error),
+    #[error("Unable to read from stream recording")]
+    ReplayRead(std::io::Error),
     #[error("Unable to read utf8 from response body")]
     BadItemEncoding(std::str::Utf8Error),
     #[error("Unable to deserialize next stream item as JSON: {inner}")]
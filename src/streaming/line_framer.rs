@@ -0,0 +1,88 @@
+use bytes::BytesMut;
+
+use super::error::CompaniesHouseStreamingNextError;
+
+/// Default upper bound on the size of a single stream item, in bytes.
+pub const DEFAULT_MAX_LINE_LENGTH: usize = 8 * 1024 * 1024;
+
+/// Splits a chunked response body into newline delimited lines.
+///
+/// The framer remembers how much of the buffer has already been searched for a
+/// newline, so an item split across many chunks is only ever scanned once.
+pub struct LineFramer {
+    buffer: BytesMut,
+    scanned: usize,
+    max_line_length: usize,
+    /// Whether the rest of an oversized line is being skipped, up to and
+    /// including its newline.
+    discarding: bool,
+}
+
+impl LineFramer {
+    pub fn new(max_line_length: usize) -> Self {
+        Self {
+            buffer: BytesMut::new(),
+            scanned: 0,
+            max_line_length,
+            discarding: false,
+        }
+    }
+
+    /// Whether there are no buffered bytes waiting to be framed, and no
+    /// oversized line is partway through being skipped.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty() && !self.discarding
+    }
+
+    /// Appends a response chunk to the end of the buffer.
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// Takes the next complete line from the buffer, including its trailing newline.
+    ///
+    /// Returns `Ok(None)` if more data is required. Fails if a line, excluding
+    /// its newline, is or is guaranteed to become longer than the maximum line
+    /// length. The rest of that line is then skipped, so framing resumes with
+    /// the line after it.
+    pub fn next_line(&mut self) -> Result<Option<BytesMut>, CompaniesHouseStreamingNextError> {
+        loop {
+            match memchr::memchr(b'\n', &self.buffer[self.scanned..]) {
+                Some(offset) => {
+                    let length = self.scanned + offset;
+                    self.scanned = 0;
+                    let line = self.buffer.split_to(length + 1);
+                    if self.discarding {
+                        self.discarding = false;
+                        continue;
+                    }
+                    if length > self.max_line_length {
+                        return Err(self.line_too_long());
+                    }
+                    return Ok(Some(line));
+                }
+                None if self.discarding => {
+                    self.buffer.clear();
+                    self.scanned = 0;
+                    return Ok(None);
+                }
+                None => {
+                    self.scanned = self.buffer.len();
+                    if self.scanned > self.max_line_length {
+                        self.buffer.clear();
+                        self.scanned = 0;
+                        self.discarding = true;
+                        return Err(self.line_too_long());
+                    }
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    fn line_too_long(&self) -> CompaniesHouseStreamingNextError {
+        CompaniesHouseStreamingNextError::LineTooLong {
+            max_line_length: self.max_line_length,
+        }
+    }
+}
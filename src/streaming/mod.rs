@@ -1,8 +1,19 @@
 mod client;
 mod error;
+mod line_framer;
+mod multiplexer;
 pub mod operation;
+mod record;
+mod replay;
 mod stream_connection;
 
 pub use client::CompaniesHouseStreamingClient;
 pub use error::{CompaniesHouseStreamingConnectionError, CompaniesHouseStreamingNextError};
+pub use line_framer::{LineFramer, DEFAULT_MAX_LINE_LENGTH};
+pub use multiplexer::{
+    Multiplexed, MultiplexedStreamError, StreamMultiplexer, StreamMultiplexerError,
+    DEFAULT_MAX_CONNECTIONS,
+};
+pub use record::StreamRecorder;
+pub use replay::ReplayStream;
 pub use stream_connection::StreamConnection;
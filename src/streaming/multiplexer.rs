@@ -0,0 +1,194 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use thiserror::Error;
+use tokio::{sync::mpsc, task::JoinHandle};
+
+use super::{
+    operation::{CompaniesHouseStreamingOperation, StreamItem},
+    CompaniesHouseStreamingClient, CompaniesHouseStreamingConnectionError,
+    CompaniesHouseStreamingNextError,
+};
+
+/// Number of streams a multiplexer will hold open at once unless configured otherwise.
+pub const DEFAULT_MAX_CONNECTIONS: usize = 2;
+
+#[derive(Debug, Error)]
+pub enum StreamMultiplexerError {
+    #[error("Already subscribed to {0}")]
+    AlreadySubscribed(&'static str),
+    #[error("Subscribing would exceed the limit of {max_connections} concurrent connections")]
+    TooManyConnections { max_connections: usize },
+}
+
+/// An error from one of the multiplexed streams. The stream is reconnected
+/// from its latest timepoint after any error.
+#[derive(Debug, Error)]
+pub enum MultiplexedStreamError {
+    #[error(transparent)]
+    Connection(#[from] CompaniesHouseStreamingConnectionError),
+    #[error(transparent)]
+    Next(#[from] CompaniesHouseStreamingNextError),
+}
+
+/// An item or error from one of the multiplexed streams, tagged with the
+/// endpoint it was read from.
+#[derive(Debug)]
+pub struct Multiplexed<T> {
+    pub endpoint: &'static str,
+    pub result: Result<T, MultiplexedStreamError>,
+}
+
+/// Consumes several streaming endpoints at once through a single client,
+/// merging their items into one sequence of `T`.
+///
+/// Each endpoint runs in its own task, resuming from the latest timepoint it
+/// has seen whenever its connection drops. Must be used within a tokio runtime.
+pub struct StreamMultiplexer<T> {
+    client: CompaniesHouseStreamingClient,
+    max_connections: usize,
+    reconnect_delay: Duration,
+    sender: mpsc::Sender<Multiplexed<T>>,
+    receiver: mpsc::Receiver<Multiplexed<T>>,
+    timepoints: Arc<Mutex<HashMap<&'static str, Option<usize>>>>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl<T: Send + 'static> StreamMultiplexer<T> {
+    pub fn new(client: CompaniesHouseStreamingClient) -> Self {
+        let (sender, receiver) = mpsc::channel(64);
+        Self {
+            client,
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            reconnect_delay: Duration::from_secs(5),
+            sender,
+            receiver,
+            timepoints: Default::default(),
+            tasks: Vec::new(),
+        }
+    }
+
+    /// Maximum number of endpoints that can be subscribed to, matching the
+    /// concurrent connection limit of the stream key.
+    pub fn max_connections(mut self, max_connections: usize) -> Self {
+        self.max_connections = max_connections;
+        self
+    }
+
+    /// How long to wait before reconnecting an endpoint after an error.
+    pub fn reconnect_delay(mut self, duration: Duration) -> Self {
+        self.reconnect_delay = duration;
+        self
+    }
+
+    /// Start streaming `operation` from `timepoint`, converting each item into
+    /// `T` with `wrap`, e.g. a variant of an enum over every subscribed endpoint.
+    pub fn subscribe<O>(
+        &mut self,
+        operation: O,
+        timepoint: Option<usize>,
+        wrap: fn(StreamItem<O::Data>) -> T,
+    ) -> Result<(), StreamMultiplexerError>
+    where
+        O: CompaniesHouseStreamingOperation + Clone + Send + 'static,
+        O::Data: Send,
+    {
+        let endpoint = operation.endpoint();
+        {
+            let mut timepoints = self.timepoints.lock().unwrap();
+            if timepoints.contains_key(endpoint) {
+                return Err(StreamMultiplexerError::AlreadySubscribed(endpoint));
+            }
+            if timepoints.len() >= self.max_connections {
+                return Err(StreamMultiplexerError::TooManyConnections {
+                    max_connections: self.max_connections,
+                });
+            }
+            timepoints.insert(endpoint, timepoint);
+        }
+
+        let client = self.client.clone();
+        let sender = self.sender.clone();
+        let timepoints = self.timepoints.clone();
+        let reconnect_delay = self.reconnect_delay;
+
+        self.tasks.push(tokio::spawn(async move {
+            let set_timepoint = |timepoint| {
+                timepoints.lock().unwrap().insert(endpoint, Some(timepoint));
+            };
+            let send = |result| sender.send(Multiplexed { endpoint, result });
+
+            loop {
+                let timepoint = timepoints.lock().unwrap()[endpoint];
+                let mut stream = match client.stream(operation.clone(), timepoint).await {
+                    Ok(stream) => stream,
+                    Err(err) => {
+                        if send(Err(err.into())).await.is_err() {
+                            return;
+                        }
+                        #[cfg(feature = "metrics")]
+                        crate::telemetry::record_reconnect(endpoint);
+                        tokio::time::sleep(reconnect_delay).await;
+                        continue;
+                    }
+                };
+
+                loop {
+                    let result = match stream.next().await {
+                        Ok(item) => {
+                            set_timepoint(item.event.timepoint + 1);
+                            Ok(wrap(item))
+                        }
+                        Err(CompaniesHouseStreamingNextError::BadItemData { inner, value }) => {
+                            set_timepoint(value.event.timepoint + 1);
+                            Err(
+                                CompaniesHouseStreamingNextError::BadItemData { inner, value }
+                                    .into(),
+                            )
+                        }
+                        Err(err) => {
+                            log::info!(endpoint; "Stream error, reconnecting: {err}");
+                            if send(Err(err.into())).await.is_err() {
+                                return;
+                            }
+                            break;
+                        }
+                    };
+
+                    if send(result).await.is_err() {
+                        return;
+                    }
+                }
+
+                #[cfg(feature = "metrics")]
+                crate::telemetry::record_reconnect(endpoint);
+                tokio::time::sleep(reconnect_delay).await;
+            }
+        }));
+
+        Ok(())
+    }
+
+    /// The timepoint each subscribed endpoint will resume from if reconnected.
+    pub fn timepoints(&self) -> HashMap<&'static str, Option<usize>> {
+        self.timepoints.lock().unwrap().clone()
+    }
+
+    /// The next item or error from any subscribed endpoint.
+    pub async fn next(&mut self) -> Option<Multiplexed<T>> {
+        if self.tasks.is_empty() {
+            return None;
+        }
+        self.receiver.recv().await
+    }
+}
+
+impl<T> Drop for StreamMultiplexer<T> {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
@@ -2,6 +2,7 @@ use crate::types::CompanyProfile;
 
 use super::CompaniesHouseStreamingOperation;
 
+#[derive(Debug, Clone, Copy)]
 pub struct StreamCompanies;
 
 impl CompaniesHouseStreamingOperation for StreamCompanies {
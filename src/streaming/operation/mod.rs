@@ -1,4 +1,6 @@
-use serde::{de::DeserializeOwned, Deserialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{macros::string_enum, types::Timestamp};
 
 pub mod companies;
 pub mod filings;
@@ -13,23 +15,23 @@ pub trait CompaniesHouseStreamingOperation {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StreamItem<Data> {
     pub data: Data,
     pub event: StreamEvent,
     pub resource_id: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StreamEvent {
-    pub published_at: String,
+    pub published_at: Timestamp,
     pub timepoint: usize,
     pub r#type: StreamEventType,
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum StreamEventType {
-    Changed,
-    Deleted,
+string_enum! {
+    pub enum StreamEventType {
+        Changed = "changed",
+        Deleted = "deleted",
+    }
 }
@@ -0,0 +1,83 @@
+#[cfg(feature = "gzip")]
+use flate2::{write::GzEncoder, Compression};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+/// Writes raw stream items to an NDJSON file, one item per line.
+///
+/// Attach to a connection with [`StreamConnection::with_recorder`](super::StreamConnection::with_recorder)
+/// and read back with [`ReplayStream`](super::ReplayStream).
+pub struct StreamRecorder {
+    writer: Writer,
+}
+
+enum Writer {
+    Plain(Box<dyn Write + Send>),
+    #[cfg(feature = "gzip")]
+    Gzip(BufWriter<GzEncoder<File>>),
+}
+
+impl Writer {
+    fn get(&mut self) -> &mut dyn Write {
+        match self {
+            Self::Plain(writer) => writer,
+            #[cfg(feature = "gzip")]
+            Self::Gzip(writer) => writer,
+        }
+    }
+}
+
+impl StreamRecorder {
+    /// Record to any writer, e.g. an in-memory buffer.
+    pub fn new(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            writer: Writer::Plain(Box::new(writer)),
+        }
+    }
+
+    /// Create (or truncate) an uncompressed NDJSON file.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+
+    /// Create (or truncate) a gzip compressed NDJSON file. Requires the `gzip`
+    /// feature.
+    ///
+    /// Call [`finish`](Self::finish) to write the gzip trailer. Dropping the
+    /// recorder also writes it, but ignores any error.
+    #[cfg(feature = "gzip")]
+    pub fn create_gzip(path: impl AsRef<Path>) -> io::Result<Self> {
+        let encoder = GzEncoder::new(File::create(path)?, Compression::default());
+        Ok(Self {
+            writer: Writer::Gzip(BufWriter::new(encoder)),
+        })
+    }
+
+    pub(super) fn record(&mut self, line: &str) -> io::Result<()> {
+        let writer = self.writer.get();
+        writer.write_all(line.as_bytes())?;
+        writer.write_all(b"\n")
+    }
+
+    /// Flush any buffered items to the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.get().flush()
+    }
+
+    /// Flush any buffered items and, for gzip recordings, write the trailer,
+    /// reporting any error which dropping the recorder would ignore.
+    pub fn finish(self) -> io::Result<()> {
+        match self.writer {
+            Writer::Plain(mut writer) => writer.flush(),
+            #[cfg(feature = "gzip")]
+            Writer::Gzip(writer) => writer
+                .into_inner()
+                .map_err(io::IntoInnerError::into_error)?
+                .finish()?
+                .sync_all(),
+        }
+    }
+}
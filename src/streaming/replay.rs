@@ -0,0 +1,122 @@
+#[cfg(feature = "gzip")]
+use flate2::read::MultiGzDecoder;
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::Path,
+    str::from_utf8,
+    time::Duration,
+};
+
+use super::{
+    error::CompaniesHouseStreamingNextError,
+    operation::{CompaniesHouseStreamingOperation, StreamItem},
+    stream_connection::parse_stream_item,
+};
+use crate::types::shared::AsTimestamp;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Reads stream items back from an NDJSON recording made with a
+/// [`StreamRecorder`](super::StreamRecorder), yielding the same results a live
+/// [`StreamConnection`](super::StreamConnection) would have.
+///
+/// Reading is blocking, so [`next`](Self::next) blocks the current thread
+/// while it reads and decompresses the next line. This is usually brief for a
+/// local file, but run slow readers on a blocking thread, e.g. with
+/// `tokio::task::spawn_blocking`.
+pub struct ReplayStream<O: CompaniesHouseStreamingOperation> {
+    reader: Box<dyn BufRead + Send>,
+    line: Vec<u8>,
+    real_time: bool,
+    last_published_at: Option<i64>,
+    _operation: O,
+}
+
+impl<O: CompaniesHouseStreamingOperation> ReplayStream<O> {
+    /// Replay from any buffered reader of uncompressed NDJSON.
+    pub fn new(operation: O, reader: impl BufRead + Send + 'static) -> Self {
+        Self {
+            reader: Box::new(reader),
+            line: Vec::new(),
+            real_time: false,
+            last_published_at: None,
+            _operation: operation,
+        }
+    }
+
+    /// Open a recording, transparently decompressing it if it is gzipped.
+    ///
+    /// Gzipped recordings need the `gzip` feature, and are otherwise rejected
+    /// with an [`io::ErrorKind::Unsupported`] error.
+    pub fn open(operation: O, path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let reader: Box<dyn BufRead + Send> = if reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+            #[cfg(feature = "gzip")]
+            {
+                Box::new(BufReader::new(MultiGzDecoder::new(reader)))
+            }
+            #[cfg(not(feature = "gzip"))]
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "gzip recordings need the gzip feature",
+            ));
+        } else {
+            Box::new(reader)
+        };
+        Ok(Self::new(operation, reader))
+    }
+
+    /// When enabled, wait between items for the gap between their
+    /// `published_at` times, imitating the pace of the original stream.
+    pub fn real_time(self, enabled: bool) -> Self {
+        Self {
+            real_time: enabled,
+            ..self
+        }
+    }
+
+    pub async fn next(&mut self) -> Result<StreamItem<O::Data>, CompaniesHouseStreamingNextError> {
+        loop {
+            self.line.clear();
+            let read = self
+                .reader
+                .read_until(b'\n', &mut self.line)
+                .map_err(CompaniesHouseStreamingNextError::ReplayRead)?;
+            if read == 0 {
+                return Err(CompaniesHouseStreamingNextError::StreamComplete);
+            }
+
+            let str = from_utf8(&self.line)
+                .map_err(CompaniesHouseStreamingNextError::BadItemEncoding)?
+                .trim();
+            if str.is_empty() {
+                continue;
+            }
+
+            let result = parse_stream_item::<O>(str, None);
+
+            if self.real_time {
+                let published_at = match &result {
+                    Ok(item) => item.event.published_at.as_timestamp(),
+                    Err(CompaniesHouseStreamingNextError::BadItemData { value, .. }) => {
+                        value.event.published_at.as_timestamp()
+                    }
+                    Err(_) => None,
+                }
+                .map(|published_at| published_at.unix_seconds());
+                if let Some(published_at) = published_at {
+                    if let Some(last) = self.last_published_at {
+                        if published_at > last {
+                            let delay = Duration::from_secs((published_at - last) as u64);
+                            tokio::time::sleep(delay).await;
+                        }
+                    }
+                    self.last_published_at = Some(published_at);
+                }
+            }
+
+            return result;
+        }
+    }
+}
@@ -1,48 +1,89 @@
-use bytes::BytesMut;
 use std::{str::from_utf8, time::Duration};
 use tokio::time::timeout;
 
-use crate::streaming::operation::StreamItem;
+use crate::{
+    streaming::operation::StreamItem,
+    unknown_fields::{self, UnknownFieldReporter},
+};
 
-use super::{error::CompaniesHouseStreamingNextError, operation::CompaniesHouseStreamingOperation};
+use super::{
+    error::CompaniesHouseStreamingNextError, line_framer::LineFramer,
+    operation::CompaniesHouseStreamingOperation, record::StreamRecorder,
+};
 
 pub struct StreamConnection<O: CompaniesHouseStreamingOperation> {
-    pub(super) buffer: BytesMut,
+    pub(super) framer: LineFramer,
     pub(super) response: reqwest::Response,
     pub(super) max_chunk_timeout: Duration,
+    pub(super) recorder: Option<StreamRecorder>,
+    pub(super) unknown_fields: Option<UnknownFieldReporter>,
+    /// Span of the connection, which records the latest timepoint read.
+    #[cfg(feature = "tracing")]
+    pub(super) span: tracing::Span,
     pub(super) _operation: O,
 }
 
 impl<O: CompaniesHouseStreamingOperation> StreamConnection<O> {
+    /// Tee every raw stream item read from this connection into a recorder, for
+    /// later use with [`ReplayStream`](super::ReplayStream).
+    pub fn with_recorder(self, recorder: StreamRecorder) -> Self {
+        Self {
+            recorder: Some(recorder),
+            ..self
+        }
+    }
+
+    /// Stop recording and return the recorder, e.g. to
+    /// [`finish`](StreamRecorder::finish) it.
+    pub fn take_recorder(&mut self) -> Option<StreamRecorder> {
+        self.recorder.take()
+    }
+
     pub async fn next(&mut self) -> Result<StreamItem<O::Data>, CompaniesHouseStreamingNextError> {
+        #[cfg(feature = "tracing")]
+        let span = self.span.clone();
+        let next = self.next_item();
+        #[cfg(feature = "tracing")]
+        let next = tracing::Instrument::instrument(next, span);
+        let item = next.await;
+
+        #[cfg(feature = "metrics")]
+        if let Ok(item) = &item {
+            crate::telemetry::record_stream_item(O::ENDPOINT_PATH, &item.event.published_at);
+        }
+        #[cfg(feature = "tracing")]
+        if let Ok(item) = &item {
+            self.span.record("timepoint", item.event.timepoint);
+            tracing::debug!(
+                parent: &self.span,
+                timepoint = item.event.timepoint,
+                resource_id = item.resource_id,
+                "Stream item received"
+            );
+        }
+        item
+    }
+
+    async fn next_item(&mut self) -> Result<StreamItem<O::Data>, CompaniesHouseStreamingNextError> {
         loop {
-            if let Some((index, _)) = self.buffer.iter().enumerate().find(|(_, c)| **c == b'\n') {
-                let left = self.buffer.split_to(index + 1);
-                let str = from_utf8(&left)
+            while let Some(line) = self.framer.next_line()? {
+                let str = from_utf8(&line)
                     .map_err(CompaniesHouseStreamingNextError::BadItemEncoding)?
                     .trim();
                 if str.is_empty() {
                     log::trace!("Buffer contains empty line");
-                } else {
-                    log::trace!(length = str.len(); "Buffer contains next stream item");
-
-                    let value_err = match serde_json::from_str(str) {
-                        Ok(data) => return Ok(data),
-                        Err(err) => err,
-                    };
-
-                    if let Ok(value) = serde_json::from_str(str) {
-                        return Err(CompaniesHouseStreamingNextError::BadItemData {
-                            inner: value_err,
-                            value,
-                        });
-                    };
-
-                    return Err(CompaniesHouseStreamingNextError::BadItemJson {
-                        inner: value_err,
-                        text: str.to_owned(),
-                    });
+                    continue;
                 }
+
+                log::trace!(length = str.len(); "Buffer contains next stream item");
+
+                if let Some(recorder) = &mut self.recorder {
+                    recorder
+                        .record(str)
+                        .map_err(CompaniesHouseStreamingNextError::RecordItem)?;
+                }
+
+                return parse_stream_item::<O>(str, self.unknown_fields.as_ref());
             }
 
             log::trace!("Buffer contains no items, reading next response chunk");
@@ -60,11 +101,40 @@ impl<O: CompaniesHouseStreamingOperation> StreamConnection<O> {
 
             log::trace!(bytes = chunk.len(); "Stream chunk received");
 
-            if self.buffer.is_empty() && chunk.len() == 1 && chunk[0] == b'\n' {
+            if self.framer.is_empty() && chunk.len() == 1 && chunk[0] == b'\n' {
                 log::info!("Heartbeat chunk received");
+                #[cfg(feature = "tracing")]
+                tracing::trace!("Heartbeat received");
+                #[cfg(feature = "metrics")]
+                crate::telemetry::record_heartbeat(O::ENDPOINT_PATH);
             } else {
-                self.buffer.extend_from_slice(&chunk);
+                self.framer.push(&chunk);
             }
         }
     }
 }
+
+/// Parse a single raw stream line, falling back to untyped JSON to give more
+/// detail when the data does not match the operation data type.
+pub(super) fn parse_stream_item<O: CompaniesHouseStreamingOperation>(
+    str: &str,
+    unknown_fields: Option<&UnknownFieldReporter>,
+) -> Result<StreamItem<O::Data>, CompaniesHouseStreamingNextError> {
+    let operation = unknown_fields::operation_name::<O>();
+    let value_err = match unknown_fields::from_str(str, unknown_fields, operation) {
+        Ok(data) => return Ok(data),
+        Err(err) => err,
+    };
+
+    if let Ok(value) = serde_json::from_str(str) {
+        return Err(CompaniesHouseStreamingNextError::BadItemData {
+            inner: value_err,
+            value,
+        });
+    };
+
+    Err(CompaniesHouseStreamingNextError::BadItemJson {
+        inner: value_err,
+        text: str.to_owned(),
+    })
+}
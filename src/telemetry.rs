@@ -0,0 +1,93 @@
+//! Metrics recorded through the [`metrics`] facade, enabled with the `metrics`
+//! feature. Install a recorder, e.g. a Prometheus exporter, to collect them.
+
+#[cfg(feature = "public-data")]
+use std::time::Duration;
+#[cfg(feature = "streaming")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "public-data")]
+use crate::transport::{HttpResponse, HttpTransportError};
+#[cfg(feature = "streaming")]
+use crate::types::{shared::AsTimestamp, Timestamp};
+
+/// Public data requests, labelled by `operation` and `status` class.
+pub const REQUESTS_TOTAL: &str = "companies_house_requests_total";
+/// Public data request latency in seconds, labelled like [`REQUESTS_TOTAL`].
+pub const REQUEST_DURATION_SECONDS: &str = "companies_house_request_duration_seconds";
+/// The `X-Ratelimit-Remain` header of the latest public data response,
+/// labelled by `operation` and `api_key`, the key's index in the
+/// [`ApiKeyPool`](crate::ApiKeyPool), as each key has its own rate limit.
+pub const RATE_LIMIT_REMAINING: &str = "companies_house_rate_limit_remaining";
+/// Stream items read, labelled by `endpoint`.
+pub const STREAM_ITEMS_TOTAL: &str = "companies_house_stream_items_total";
+/// Stream heartbeats received, labelled by `endpoint`.
+pub const STREAM_HEARTBEATS_TOTAL: &str = "companies_house_stream_heartbeats_total";
+/// Stream reconnections by a multiplexer, labelled by `endpoint`.
+pub const STREAM_RECONNECTS_TOTAL: &str = "companies_house_stream_reconnects_total";
+/// Seconds between an item's `published_at` and it being read, labelled by `endpoint`.
+pub const STREAM_LAG_SECONDS: &str = "companies_house_stream_lag_seconds";
+
+#[cfg(feature = "public-data")]
+pub(crate) fn record_response(
+    operation: &'static str,
+    api_key: usize,
+    response: &Result<HttpResponse, HttpTransportError>,
+    duration: Duration,
+) {
+    let status = match response {
+        Ok(response) => match response.status.as_u16() {
+            100..=199 => "1xx",
+            200..=299 => "2xx",
+            300..=399 => "3xx",
+            400..=499 => "4xx",
+            _ => "5xx",
+        },
+        Err(_) => "error",
+    };
+    let labels = [("operation", operation), ("status", status)];
+    metrics::counter!(REQUESTS_TOTAL, &labels).increment(1);
+    metrics::histogram!(REQUEST_DURATION_SECONDS, &labels).record(duration.as_secs_f64());
+
+    let remaining = response.as_ref().ok().and_then(|response| {
+        response
+            .headers
+            .get("x-ratelimit-remain")?
+            .to_str()
+            .ok()?
+            .parse::<f64>()
+            .ok()
+    });
+    if let Some(remaining) = remaining {
+        metrics::gauge!(
+            RATE_LIMIT_REMAINING,
+            "operation" => operation,
+            "api_key" => api_key.to_string()
+        )
+        .set(remaining);
+    }
+}
+
+#[cfg(feature = "streaming")]
+pub(crate) fn record_stream_item(endpoint: &'static str, published_at: &Timestamp) {
+    metrics::counter!(STREAM_ITEMS_TOTAL, "endpoint" => endpoint).increment(1);
+
+    let Some(published_at) = published_at.as_timestamp() else {
+        return;
+    };
+    let published_at = published_at.unix_seconds() as f64 + published_at.nanosecond() as f64 / 1e9;
+    if let Ok(now) = SystemTime::now().duration_since(UNIX_EPOCH) {
+        metrics::gauge!(STREAM_LAG_SECONDS, "endpoint" => endpoint)
+            .set(now.as_secs_f64() - published_at);
+    }
+}
+
+#[cfg(feature = "streaming")]
+pub(crate) fn record_heartbeat(endpoint: &'static str) {
+    metrics::counter!(STREAM_HEARTBEATS_TOTAL, "endpoint" => endpoint).increment(1);
+}
+
+#[cfg(feature = "streaming")]
+pub(crate) fn record_reconnect(endpoint: &'static str) {
+    metrics::counter!(STREAM_RECONNECTS_TOTAL, "endpoint" => endpoint).increment(1);
+}
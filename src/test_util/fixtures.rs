@@ -0,0 +1,86 @@
+//! Sample response bodies for every implemented public data operation, served
+//! by default from [`MockPublicDataServer`](super::MockPublicDataServer), and
+//! sample stream items.
+//!
+//! The public data samples can be re-recorded from the live API with the
+//! `record_fixtures` example.
+
+use serde_json::json;
+
+/// Company number of [`COMPANY_PROFILE`] and the other company scoped fixtures.
+pub const COMPANY_NUMBER: &str = "01234567";
+
+/// `GET /company/{company_number}`
+pub const COMPANY_PROFILE: &str = include_str!("fixtures/company_profile.json");
+
+/// `GET /company/{company_number}/registered-office-address`
+pub const REGISTERED_OFFICE_ADDRESS: &str = include_str!("fixtures/registered_office_address.json");
+
+/// `GET /company/{company_number}/officers`
+pub const OFFICER_LIST: &str = include_str!("fixtures/officer_list.json");
+
+/// `GET /company/{company_number}/filing-history`
+pub const FILING_HISTORY_LIST: &str = include_str!("fixtures/filing_history.json");
+
+/// `GET /company/{company_number}/charges`
+pub const CHARGE_LIST: &str = include_str!("fixtures/charge_list.json");
+
+/// `GET /company/{company_number}/persons-with-significant-control`
+pub const PERSONS_WITH_SIGNIFICANT_CONTROL_LIST: &str =
+    include_str!("fixtures/persons_with_significant_control_list.json");
+
+/// `GET /company/{company_number}/insolvency`
+pub const COMPANY_INSOLVENCY: &str = include_str!("fixtures/company_insolvency.json");
+
+/// `GET /company/{company_number}/registers`
+pub const COMPANY_REGISTERS: &str = include_str!("fixtures/company_registers.json");
+
+/// `GET /search/companies`
+pub const COMPANY_SEARCH: &str = include_str!("fixtures/company_search.json");
+
+/// `GET /search/officers`
+pub const OFFICER_SEARCH: &str = include_str!("fixtures/officer_search.json");
+
+/// `GET /search/disqualified-officers`
+pub const DISQUALIFIED_OFFICER_SEARCH: &str =
+    include_str!("fixtures/disqualified_officer_search.json");
+
+/// An NDJSON recording of `GET /filings` stream items, as written by a
+/// [`StreamRecorder`](crate::streaming::StreamRecorder).
+pub const FILINGS_STREAM: &str = include_str!("fixtures/filings_stream.ndjson");
+
+/// A `GET /companies` stream item at `timepoint`, with [`COMPANY_PROFILE`] as
+/// its data, for pushing to a [`MockStreamingServer`](super::MockStreamingServer).
+pub fn company(timepoint: usize) -> serde_json::Value {
+    json!({
+        "data": serde_json::from_str::<serde_json::Value>(COMPANY_PROFILE).unwrap(),
+        "event": {
+            "published_at": "2024-11-27T12:00:00",
+            "timepoint": timepoint,
+            "type": "changed"
+        },
+        "resource_id": COMPANY_NUMBER
+    })
+}
+
+/// A `GET /filings` stream item at `timepoint`, for pushing to a
+/// [`MockStreamingServer`](super::MockStreamingServer). Each timepoint is
+/// published one second after the previous one.
+pub fn filing(timepoint: usize) -> serde_json::Value {
+    let seconds = timepoint % 3600;
+    json!({
+        "data": {
+            "category": "accounts",
+            "date": "2024-11-27",
+            "description": "accounts-with-accounts-type-micro-entity",
+            "transaction_id": format!("MzQ1{timepoint}"),
+            "type": "AA"
+        },
+        "event": {
+            "published_at": format!("2024-11-27T12:{:02}:{:02}", seconds / 60, seconds % 60),
+            "timepoint": timepoint,
+            "type": "changed"
+        },
+        "resource_id": format!("MzQ1{timepoint}")
+    })
+}
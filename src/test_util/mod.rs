@@ -0,0 +1,14 @@
+//! In-process mock servers for testing code built on this crate without a live
+//! API key. Enabled with the `test-util` feature.
+
+pub mod fixtures;
+mod http;
+mod public_data;
+mod streaming;
+
+pub use public_data::{MockPublicDataRequest, MockPublicDataServer, MockResponse};
+pub use streaming::{MockStreamConnection, MockStreamRequest, MockStreamStep, MockStreamingServer};
+
+/// Fails to compile unless `T` can be cloned and shared across tasks, e.g.
+/// `assert_send_sync_clone::<CompaniesHousePublicDataClient>()`.
+pub fn assert_send_sync_clone<T: Send + Sync + Clone + 'static>() {}
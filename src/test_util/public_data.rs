@@ -0,0 +1,196 @@
+use reqwest::StatusCode;
+use std::{
+    collections::{HashMap, VecDeque},
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+use tokio::{
+    io,
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+use super::{fixtures, http};
+
+/// A canned response from the mock public data server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockResponse {
+    pub status: StatusCode,
+    pub body: String,
+}
+
+impl MockResponse {
+    pub fn new(status: StatusCode, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: body.into(),
+        }
+    }
+
+    /// A `200 OK` response with the given JSON body.
+    pub fn ok(body: impl Into<String>) -> Self {
+        Self::new(StatusCode::OK, body)
+    }
+
+    /// A response with an empty body, e.g. to inject a `429` or `500`.
+    pub fn status(status: StatusCode) -> Self {
+        Self::new(status, "")
+    }
+}
+
+/// A request received by the mock server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockPublicDataRequest {
+    pub path: String,
+    pub query: HashMap<String, String>,
+}
+
+struct MockPublicDataState {
+    api_key: String,
+    routes: HashMap<String, MockResponse>,
+    responses: VecDeque<MockResponse>,
+    requests: Vec<MockPublicDataRequest>,
+}
+
+/// An in-process server emulating the Companies House public data API.
+///
+/// Every implemented operation is answered with the matching sample from
+/// [`fixtures`], with company scoped paths only found for
+/// [`fixtures::COMPANY_NUMBER`]. Requests with the wrong API key get `401`.
+pub struct MockPublicDataServer {
+    address: SocketAddr,
+    state: Arc<Mutex<MockPublicDataState>>,
+    task: JoinHandle<()>,
+}
+
+impl MockPublicDataServer {
+    /// Start listening on an ephemeral local port, accepting only `api_key`.
+    pub async fn start(api_key: &str) -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(MockPublicDataState {
+            api_key: api_key.to_owned(),
+            routes: HashMap::new(),
+            responses: VecDeque::new(),
+            requests: Vec::new(),
+        }));
+
+        let task = tokio::spawn({
+            let state = state.clone();
+            async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    tokio::spawn(handle_connection(stream, state.clone()));
+                }
+            }
+        });
+
+        Ok(Self {
+            address,
+            state,
+            task,
+        })
+    }
+
+    /// The URL to pass to [`CompaniesHousePublicDataClient::base_url`](crate::CompaniesHousePublicDataClient::base_url).
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Answer every request to `path`, e.g. `/company/SC123456`, with `response`.
+    pub fn set_response(&self, path: &str, response: MockResponse) {
+        self.state
+            .lock()
+            .unwrap()
+            .routes
+            .insert(path.to_owned(), response);
+    }
+
+    /// Answer the next request, whatever its path, with `response`. Pushed
+    /// responses are used in the order they were pushed.
+    pub fn push_response(&self, response: MockResponse) {
+        self.state.lock().unwrap().responses.push_back(response);
+    }
+
+    /// Every request received so far.
+    pub fn requests(&self) -> Vec<MockPublicDataRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Drop for MockPublicDataServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, state: Arc<Mutex<MockPublicDataState>>) {
+    if let Err(err) = serve(&mut stream, state).await {
+        log::debug!("Mock public data connection closed: {err}");
+    }
+}
+
+async fn serve(stream: &mut TcpStream, state: Arc<Mutex<MockPublicDataState>>) -> io::Result<()> {
+    let Some(request) = http::read_request(stream).await? else {
+        return Ok(());
+    };
+
+    let response = {
+        let mut state = state.lock().unwrap();
+        state.requests.push(MockPublicDataRequest {
+            path: request.path.clone(),
+            query: request.query.clone(),
+        });
+
+        let authorization = request.headers.get("authorization");
+        if authorization != Some(&state.api_key)
+            && authorization != Some(&http::basic_auth(&state.api_key))
+        {
+            MockResponse::new(
+                StatusCode::UNAUTHORIZED,
+                r#"{"error":"Invalid Authorization","type":"ch:service"}"#,
+            )
+        } else if let Some(response) = state.responses.pop_front() {
+            response
+        } else if let Some(response) = state.routes.get(&request.path) {
+            response.clone()
+        } else {
+            default_response(&request.path)
+        }
+    };
+
+    http::write_response(stream, response.status, response.body.as_bytes()).await
+}
+
+fn default_response(path: &str) -> MockResponse {
+    let company = format!("/company/{}", fixtures::COMPANY_NUMBER);
+    let registered_office_address = format!("{company}/registered-office-address");
+    let officers = format!("{company}/officers");
+    let filing_history = format!("{company}/filing-history");
+    let charges = format!("{company}/charges");
+    let persons_with_significant_control = format!("{company}/persons-with-significant-control");
+    let insolvency = format!("{company}/insolvency");
+    let registers = format!("{company}/registers");
+
+    match path {
+        path if path == company => MockResponse::ok(fixtures::COMPANY_PROFILE),
+        path if path == registered_office_address => {
+            MockResponse::ok(fixtures::REGISTERED_OFFICE_ADDRESS)
+        }
+        path if path == officers => MockResponse::ok(fixtures::OFFICER_LIST),
+        path if path == filing_history => MockResponse::ok(fixtures::FILING_HISTORY_LIST),
+        path if path == charges => MockResponse::ok(fixtures::CHARGE_LIST),
+        path if path == persons_with_significant_control => {
+            MockResponse::ok(fixtures::PERSONS_WITH_SIGNIFICANT_CONTROL_LIST)
+        }
+        path if path == insolvency => MockResponse::ok(fixtures::COMPANY_INSOLVENCY),
+        path if path == registers => MockResponse::ok(fixtures::COMPANY_REGISTERS),
+        "/search/companies" => MockResponse::ok(fixtures::COMPANY_SEARCH),
+        "/search/officers" => MockResponse::ok(fixtures::OFFICER_SEARCH),
+        "/search/disqualified-officers" => MockResponse::ok(fixtures::DISQUALIFIED_OFFICER_SEARCH),
+        path if path.starts_with("/company/") => MockResponse::new(
+            StatusCode::NOT_FOUND,
+            r#"{"errors":[{"error":"company-profile-not-found","type":"ch:service"}]}"#,
+        ),
+        _ => MockResponse::status(StatusCode::NOT_FOUND),
+    }
+}
@@ -0,0 +1,226 @@
+use reqwest::StatusCode;
+use std::{
+    collections::{HashMap, VecDeque},
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::{
+    io,
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+use super::http;
+
+/// One step of a scripted stream connection.
+#[derive(Debug, Clone)]
+pub enum MockStreamStep {
+    /// Send the next `n` items from the requested timepoint, one item per chunk.
+    Items(usize),
+    /// Send every remaining item from the requested timepoint.
+    AllItems,
+    /// Send a single newline chunk, as the real API does when idle.
+    Heartbeat,
+    /// Send raw bytes as a single chunk, e.g. half of an item.
+    Chunk(Vec<u8>),
+    /// Wait before the next step.
+    Sleep(Duration),
+    /// Finish the response body cleanly.
+    End,
+    /// Close the socket without finishing the response body.
+    Disconnect,
+}
+
+/// How the mock server answers a single connection attempt.
+#[derive(Debug, Clone)]
+pub enum MockStreamConnection {
+    /// Respond with an empty body and this status, e.g. `429 Too Many Requests`.
+    Status(StatusCode),
+    /// Stream a `200 OK` response following these steps.
+    Steps(Vec<MockStreamStep>),
+}
+
+/// A connection attempt received by the mock server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockStreamRequest {
+    pub path: String,
+    pub timepoint: Option<usize>,
+}
+
+struct MockStreamingState {
+    authorization: String,
+    earliest_timepoint: usize,
+    heartbeat_interval: Duration,
+    items: HashMap<String, Vec<(usize, String)>>,
+    connections: VecDeque<MockStreamConnection>,
+    requests: Vec<MockStreamRequest>,
+}
+
+/// An in-process server emulating `stream.companieshouse.gov.uk`.
+///
+/// Connections are answered with the next scripted [`MockStreamConnection`].
+/// Once the script is exhausted, every item from the requested timepoint is
+/// sent followed by a heartbeat every `heartbeat_interval`. Requests with the
+/// wrong API key get `401` and timepoints before the earliest timepoint get `416`.
+pub struct MockStreamingServer {
+    address: SocketAddr,
+    state: Arc<Mutex<MockStreamingState>>,
+    task: JoinHandle<()>,
+}
+
+impl MockStreamingServer {
+    /// Start listening on an ephemeral local port, accepting only `api_key`.
+    pub async fn start(api_key: &str) -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(MockStreamingState {
+            authorization: http::basic_auth(api_key),
+            earliest_timepoint: 0,
+            heartbeat_interval: Duration::from_secs(30),
+            items: HashMap::new(),
+            connections: VecDeque::new(),
+            requests: Vec::new(),
+        }));
+
+        let task = tokio::spawn({
+            let state = state.clone();
+            async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    tokio::spawn(handle_connection(stream, state.clone()));
+                }
+            }
+        });
+
+        Ok(Self {
+            address,
+            state,
+            task,
+        })
+    }
+
+    /// The URL to pass to [`CompaniesHouseStreamingClient::base_url`](crate::streaming::CompaniesHouseStreamingClient::base_url).
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Add a raw stream item to an endpoint such as `/companies`. The item is
+    /// ordered by its `event.timepoint`.
+    pub fn push_item(&self, endpoint: &str, item: serde_json::Value) {
+        let timepoint = item["event"]["timepoint"].as_u64().unwrap_or_default() as usize;
+        let mut state = self.state.lock().unwrap();
+        let items = state.items.entry(endpoint.to_owned()).or_default();
+        let index = items.partition_point(|(t, _)| *t <= timepoint);
+        items.insert(index, (timepoint, item.to_string()));
+    }
+
+    /// Script how the next connection attempt is answered. Scripted connections
+    /// are used in the order they were pushed.
+    pub fn push_connection(&self, connection: MockStreamConnection) {
+        self.state.lock().unwrap().connections.push_back(connection);
+    }
+
+    /// Timepoints before this are answered with `416 Range Not Satisfiable`.
+    pub fn set_earliest_timepoint(&self, timepoint: usize) {
+        self.state.lock().unwrap().earliest_timepoint = timepoint;
+    }
+
+    /// How often heartbeats are sent once an unscripted connection is idle.
+    pub fn set_heartbeat_interval(&self, interval: Duration) {
+        self.state.lock().unwrap().heartbeat_interval = interval;
+    }
+
+    /// Every connection attempt received so far.
+    pub fn requests(&self) -> Vec<MockStreamRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Drop for MockStreamingServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, state: Arc<Mutex<MockStreamingState>>) {
+    if let Err(err) = serve(&mut stream, state).await {
+        log::debug!("Mock stream connection closed: {err}");
+    }
+}
+
+async fn serve(stream: &mut TcpStream, state: Arc<Mutex<MockStreamingState>>) -> io::Result<()> {
+    let Some(request) = http::read_request(stream).await? else {
+        return Ok(());
+    };
+    let timepoint = request
+        .query
+        .get("timepoint")
+        .and_then(|timepoint| timepoint.parse().ok());
+
+    let (connection, mut items, heartbeat_interval) = {
+        let mut state = state.lock().unwrap();
+        state.requests.push(MockStreamRequest {
+            path: request.path.clone(),
+            timepoint,
+        });
+
+        let connection = if request.headers.get("authorization") != Some(&state.authorization) {
+            MockStreamConnection::Status(StatusCode::UNAUTHORIZED)
+        } else if let Some(connection) = state.connections.pop_front() {
+            connection
+        } else {
+            MockStreamConnection::Steps(vec![MockStreamStep::AllItems])
+        };
+
+        let connection = match (connection, timepoint) {
+            (MockStreamConnection::Steps(_), Some(t)) if t < state.earliest_timepoint => {
+                MockStreamConnection::Status(StatusCode::RANGE_NOT_SATISFIABLE)
+            }
+            (connection, _) => connection,
+        };
+
+        let items: VecDeque<String> = state
+            .items
+            .get(&request.path)
+            .into_iter()
+            .flatten()
+            .filter(|(t, _)| timepoint.map_or(true, |timepoint| *t >= timepoint))
+            .map(|(_, item)| format!("{item}\n"))
+            .collect();
+
+        (connection, items, state.heartbeat_interval)
+    };
+
+    let steps = match connection {
+        MockStreamConnection::Status(status) => {
+            return http::write_response(stream, status, b"").await;
+        }
+        MockStreamConnection::Steps(steps) => steps,
+    };
+
+    http::write_chunked_head(stream).await?;
+    for step in steps {
+        match step {
+            MockStreamStep::Items(count) => {
+                for item in items.drain(..count.min(items.len())) {
+                    http::write_chunk(stream, item.as_bytes()).await?;
+                }
+            }
+            MockStreamStep::AllItems => {
+                for item in items.drain(..) {
+                    http::write_chunk(stream, item.as_bytes()).await?;
+                }
+            }
+            MockStreamStep::Heartbeat => http::write_chunk(stream, b"\n").await?,
+            MockStreamStep::Chunk(chunk) => http::write_chunk(stream, &chunk).await?,
+            MockStreamStep::Sleep(duration) => tokio::time::sleep(duration).await,
+            MockStreamStep::End => return http::write_chunked_end(stream).await,
+            MockStreamStep::Disconnect => return Ok(()),
+        }
+    }
+
+    loop {
+        tokio::time::sleep(heartbeat_interval).await;
+        http::write_chunk(stream, b"\n").await?;
+    }
+}
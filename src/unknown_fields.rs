@@ -0,0 +1,87 @@
+use serde::de::DeserializeOwned;
+use std::{any::type_name, fmt, sync::Arc};
+
+/// A response field which is not modelled by this crate, and was ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownField {
+    /// Name of the operation whose response contained the field, e.g. `GetCompanyProfile`.
+    pub operation: &'static str,
+    /// Dotted path to the field, e.g. `accounts.next_accounts.new_field`.
+    pub path: String,
+}
+
+/// How to report [`UnknownField`]s found when parsing responses, without
+/// failing the request.
+#[derive(Clone)]
+pub enum UnknownFieldReporter {
+    /// Log a warning for each unknown field.
+    Log,
+    /// Call a function with each unknown field.
+    Callback(Arc<dyn Fn(&UnknownField) + Send + Sync>),
+}
+
+impl UnknownFieldReporter {
+    pub fn callback(callback: impl Fn(&UnknownField) + Send + Sync + 'static) -> Self {
+        Self::Callback(Arc::new(callback))
+    }
+
+    fn report(&self, field: &UnknownField) {
+        match self {
+            Self::Log => log::warn!(
+                operation = field.operation, path = field.path.as_str();
+                "Unknown field in response"
+            ),
+            Self::Callback(callback) => callback(field),
+        }
+    }
+}
+
+impl fmt::Debug for UnknownFieldReporter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Log => f.write_str("Log"),
+            Self::Callback(_) => f.write_str("Callback(..)"),
+        }
+    }
+}
+
+/// Short type name of an operation, e.g. `GetCompanyProfile`.
+pub(crate) fn operation_name<O>() -> &'static str {
+    let name = type_name::<O>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Parse JSON as `T`, reporting any ignored fields to `reporter` if the parse succeeds.
+#[cfg(feature = "streaming")]
+pub(crate) fn from_str<T: DeserializeOwned>(
+    json: &str,
+    reporter: Option<&UnknownFieldReporter>,
+    operation: &'static str,
+) -> serde_json::Result<T> {
+    from_slice(json.as_bytes(), reporter, operation)
+}
+
+/// Parse JSON as `T`, reporting any ignored fields to `reporter` if the parse succeeds.
+pub(crate) fn from_slice<T: DeserializeOwned>(
+    json: &[u8],
+    reporter: Option<&UnknownFieldReporter>,
+    operation: &'static str,
+) -> serde_json::Result<T> {
+    let Some(reporter) = reporter else {
+        return serde_json::from_slice(json);
+    };
+
+    let mut paths = Vec::new();
+    let mut deserializer = serde_json::Deserializer::from_slice(json);
+    let value = serde_ignored::deserialize(&mut deserializer, |path| {
+        // `?` marks a `Some` in the path, which is noise for optional fields.
+        paths.push(path.to_string().replace("?.", ""))
+    })?;
+    deserializer.end()?;
+
+    for path in paths {
+        reporter.report(&UnknownField { operation, path });
+    }
+    Ok(value)
+}
@@ -0,0 +1,171 @@
+use companies_house_api::{
+    api_key::{ApiKeyCallback, ApiKeyError, ApiKeyProvider, EnvApiKey, FileApiKey, SharedApiKey},
+    operation::{
+        get_company_profile::{GetCompanyProfile, GetCompanyProfileStatusError},
+        CompaniesHousePublicDataOperationError,
+    },
+    streaming::{
+        operation::filings::StreamFilings, CompaniesHouseStreamingClient,
+        CompaniesHouseStreamingConnectionError,
+    },
+    test_util::{fixtures, MockPublicDataServer, MockStreamingServer},
+    types::CompanyNumber,
+    CompaniesHousePublicDataClient,
+};
+use std::{
+    fs,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, SystemTime},
+};
+
+const OLD_KEY: &str = "old-key";
+const NEW_KEY: &str = "new-key";
+
+fn get_company_profile() -> GetCompanyProfile {
+    GetCompanyProfile::builder()
+        .company_number(fixtures::COMPANY_NUMBER.parse::<CompanyNumber>().unwrap())
+        .build()
+}
+
+#[tokio::test]
+async fn public_data_client_uses_swapped_key() {
+    let server = MockPublicDataServer::start(NEW_KEY).await.unwrap();
+    let api_key = SharedApiKey::new(OLD_KEY);
+    let client = CompaniesHousePublicDataClient::with_api_key_provider(api_key.clone())
+        .unwrap()
+        .base_url(&server.base_url())
+        .unwrap();
+
+    assert!(matches!(
+        client.send(get_company_profile()).await,
+        Err(CompaniesHousePublicDataOperationError::Status(
+            GetCompanyProfileStatusError::Unauthorized
+        ))
+    ));
+
+    api_key.set(NEW_KEY);
+    client.send(get_company_profile()).await.unwrap();
+}
+
+#[tokio::test]
+async fn streaming_client_uses_swapped_key() {
+    let server = MockStreamingServer::start(NEW_KEY).await.unwrap();
+    let api_key = SharedApiKey::new(OLD_KEY);
+    let client = CompaniesHouseStreamingClient::with_api_key_provider(api_key.clone())
+        .base_url(server.base_url());
+
+    assert!(matches!(
+        client.stream(StreamFilings, None).await,
+        Err(CompaniesHouseStreamingConnectionError::Unauthorized)
+    ));
+
+    api_key.set(NEW_KEY);
+    client.stream(StreamFilings, None).await.unwrap();
+}
+
+#[tokio::test]
+async fn reports_provider_errors() {
+    let client = CompaniesHousePublicDataClient::with_api_key_provider(EnvApiKey::new(
+        "COMPANIES_HOUSE_API_KEY_TEST_UNSET",
+    ))
+    .unwrap();
+    assert!(matches!(
+        client.send(get_company_profile()).await,
+        Err(CompaniesHousePublicDataOperationError::ApiKey(
+            ApiKeyError::MissingEnv { .. }
+        ))
+    ));
+
+    let client =
+        CompaniesHousePublicDataClient::with_api_key_provider(SharedApiKey::new("bad\nkey"))
+            .unwrap();
+    assert!(matches!(
+        client.send(get_company_profile()).await,
+        Err(CompaniesHousePublicDataOperationError::ApiKey(
+            ApiKeyError::Invalid
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn reads_env_var_on_each_request() {
+    let name = "COMPANIES_HOUSE_API_KEY_TEST";
+    let api_key = EnvApiKey::new(name);
+    // SAFETY: no other test reads or writes this variable.
+    unsafe { std::env::set_var(name, OLD_KEY) };
+    assert_eq!(api_key.api_key().await.unwrap(), OLD_KEY);
+    unsafe { std::env::set_var(name, NEW_KEY) };
+    assert_eq!(api_key.api_key().await.unwrap(), NEW_KEY);
+}
+
+#[tokio::test]
+async fn rereads_file_when_modified() {
+    let path = std::env::temp_dir().join(format!("companies-house-api-key-{}", std::process::id()));
+    let api_key = FileApiKey::new(&path).check_interval(Duration::ZERO);
+    assert!(matches!(
+        api_key.api_key().await,
+        Err(ApiKeyError::ReadFile { .. })
+    ));
+
+    fs::write(&path, format!("{OLD_KEY}\n")).unwrap();
+    assert_eq!(api_key.api_key().await.unwrap(), OLD_KEY);
+
+    fs::write(&path, NEW_KEY).unwrap();
+    // Make sure the modification time changes even on coarse filesystems.
+    let file = fs::File::options().write(true).open(&path).unwrap();
+    file.set_modified(SystemTime::now() + Duration::from_secs(1))
+        .unwrap();
+    assert_eq!(api_key.api_key().await.unwrap(), NEW_KEY);
+
+    fs::write(&path, " \n").unwrap();
+    file.set_modified(SystemTime::now() + Duration::from_secs(2))
+        .unwrap();
+    assert!(matches!(api_key.api_key().await, Err(ApiKeyError::Empty)));
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn uses_cached_file_key_between_checks() {
+    let path = std::env::temp_dir().join(format!(
+        "companies-house-api-key-cached-{}",
+        std::process::id()
+    ));
+    fs::write(&path, OLD_KEY).unwrap();
+    let api_key = FileApiKey::new(&path).check_interval(Duration::from_secs(3600));
+    assert_eq!(api_key.api_key().await.unwrap(), OLD_KEY);
+
+    fs::write(&path, NEW_KEY).unwrap();
+    fs::File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(1))
+        .unwrap();
+    assert_eq!(api_key.api_key().await.unwrap(), OLD_KEY);
+
+    fs::remove_file(&path).unwrap();
+    assert_eq!(api_key.api_key().await.unwrap(), OLD_KEY);
+}
+
+#[tokio::test]
+async fn calls_callback_for_each_request() {
+    let server = MockPublicDataServer::start(NEW_KEY).await.unwrap();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let callback_calls = calls.clone();
+    let client =
+        CompaniesHousePublicDataClient::with_api_key_provider(ApiKeyCallback::new(move || {
+            callback_calls.fetch_add(1, Ordering::SeqCst);
+            async { Ok(NEW_KEY.to_owned()) }
+        }))
+        .unwrap()
+        .base_url(&server.base_url())
+        .unwrap();
+
+    client.send(get_company_profile()).await.unwrap();
+    client.send(get_company_profile()).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
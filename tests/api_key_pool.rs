@@ -0,0 +1,214 @@
+use companies_house_api::{
+    api_key::{ApiKeyProvider, SharedApiKey, StaticApiKey},
+    operation::{
+        get_company_profile::{GetCompanyProfile, GetCompanyProfileStatusError},
+        CompaniesHousePublicDataOperationError,
+    },
+    test_util::fixtures,
+    transport::{HttpRequest, HttpResponse, HttpTransport, HttpTransportFuture},
+    types::CompanyNumber,
+    ApiKeyPool, ApiKeyPoolExhausted, ApiKeySelection, CompaniesHousePublicDataClient,
+    CompaniesHousePublicDataClientError,
+};
+use reqwest::{
+    header::{self, HeaderMap},
+    StatusCode,
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Answers with a company profile, or a scripted status for some keys,
+/// recording which key each request used.
+#[derive(Clone, Default)]
+struct KeyedTransport {
+    statuses: Arc<Mutex<Vec<(&'static str, StatusCode)>>>,
+    keys: Arc<Mutex<Vec<String>>>,
+}
+
+impl KeyedTransport {
+    /// Answer the next request made with `key` with `status`.
+    fn fail_next(&self, key: &'static str, status: StatusCode) {
+        self.statuses.lock().unwrap().push((key, status));
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.keys.lock().unwrap().clone()
+    }
+}
+
+impl HttpTransport for KeyedTransport {
+    fn execute(&self, request: HttpRequest) -> HttpTransportFuture<'_> {
+        let key = request.headers[header::AUTHORIZATION]
+            .to_str()
+            .unwrap()
+            .to_owned();
+        let mut statuses = self.statuses.lock().unwrap();
+        let status = match statuses.iter().position(|(k, _)| *k == key) {
+            Some(index) => statuses.remove(index).1,
+            None => StatusCode::OK,
+        };
+        self.keys.lock().unwrap().push(key);
+        Box::pin(async move {
+            Ok(HttpResponse {
+                status,
+                headers: HeaderMap::new(),
+                body: fixtures::COMPANY_PROFILE.into(),
+            })
+        })
+    }
+}
+
+fn client(pool: ApiKeyPool) -> (KeyedTransport, CompaniesHousePublicDataClient) {
+    let transport = KeyedTransport::default();
+    let client = CompaniesHousePublicDataClient::with_api_key_pool(pool)
+        .unwrap()
+        .transport(transport.clone());
+    (transport, client)
+}
+
+fn get_company_profile() -> GetCompanyProfile {
+    GetCompanyProfile::builder()
+        .company_number(fixtures::COMPANY_NUMBER.parse::<CompanyNumber>().unwrap())
+        .build()
+}
+
+#[test]
+fn pool_needs_a_key() {
+    assert!(matches!(
+        ApiKeyPool::new(Vec::<String>::new()),
+        Err(CompaniesHousePublicDataClientError::NoApiKeys)
+    ));
+    assert!(matches!(
+        ApiKeyPool::new(["good", "bad\n"]),
+        Err(CompaniesHousePublicDataClientError::BadApiKey)
+    ));
+}
+
+#[tokio::test]
+async fn round_robin_uses_each_key_in_turn() {
+    let (transport, client) = client(ApiKeyPool::new(["a", "b", "c"]).unwrap());
+
+    for _ in 0..4 {
+        client.send(get_company_profile()).await.unwrap();
+    }
+
+    assert_eq!(transport.keys(), ["a", "b", "c", "a"]);
+    let requests: Vec<u64> = (client.api_key_pool().stats().iter())
+        .map(|stats| stats.requests)
+        .collect();
+    assert_eq!(requests, [2, 1, 1]);
+}
+
+#[tokio::test]
+async fn unauthorized_keys_are_quarantined() {
+    let (transport, client) = client(ApiKeyPool::new(["good", "bad"]).unwrap());
+    transport.fail_next("bad", StatusCode::UNAUTHORIZED);
+
+    client.send(get_company_profile()).await.unwrap();
+    assert!(matches!(
+        client.send(get_company_profile()).await,
+        Err(CompaniesHousePublicDataOperationError::Status(
+            GetCompanyProfileStatusError::Unauthorized
+        ))
+    ));
+    client.send(get_company_profile()).await.unwrap();
+    client.send(get_company_profile()).await.unwrap();
+
+    assert_eq!(transport.keys(), ["good", "bad", "good", "good"]);
+    let quarantined: Vec<bool> = (client.api_key_pool().stats().iter())
+        .map(|stats| stats.quarantined)
+        .collect();
+    assert_eq!(quarantined, [false, true]);
+}
+
+#[tokio::test]
+async fn last_key_is_not_quarantined() {
+    let (transport, client) = client(ApiKeyPool::new(["only"]).unwrap());
+    transport.fail_next("only", StatusCode::UNAUTHORIZED);
+
+    assert!(client.send(get_company_profile()).await.is_err());
+    client.send(get_company_profile()).await.unwrap();
+    assert!(!client.api_key_pool().stats()[0].quarantined);
+}
+
+#[tokio::test]
+async fn quarantine_ends_after_duration() {
+    let pool = ApiKeyPool::new(["good", "bad"])
+        .unwrap()
+        .quarantine_for(Duration::from_millis(50));
+    let (transport, client) = client(pool);
+    transport.fail_next("bad", StatusCode::UNAUTHORIZED);
+
+    client.send(get_company_profile()).await.unwrap();
+    client.send(get_company_profile()).await.unwrap_err();
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    client.send(get_company_profile()).await.unwrap();
+    client.send(get_company_profile()).await.unwrap();
+
+    assert_eq!(transport.keys(), ["good", "bad", "good", "bad"]);
+}
+
+#[tokio::test]
+async fn rotated_provider_key_returns_after_quarantine() {
+    let rotated = SharedApiKey::new("bad");
+    let pool = ApiKeyPool::from_providers([
+        Arc::new(StaticApiKey::new("good")) as Arc<dyn ApiKeyProvider>,
+        Arc::new(rotated.clone()),
+    ])
+    .unwrap()
+    .quarantine_for(Duration::from_millis(50));
+    let (transport, client) = client(pool);
+    transport.fail_next("bad", StatusCode::UNAUTHORIZED);
+
+    client.send(get_company_profile()).await.unwrap();
+    client.send(get_company_profile()).await.unwrap_err();
+    rotated.set("rotated");
+    client.send(get_company_profile()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    client.send(get_company_profile()).await.unwrap();
+
+    assert_eq!(transport.keys(), ["good", "bad", "good", "rotated"]);
+}
+
+#[tokio::test]
+async fn rate_limited_keys_are_skipped_until_their_window_ends() {
+    let pool = ApiKeyPool::new(["a", "b"])
+        .unwrap()
+        .rate_limit(1, Duration::from_secs(3600));
+    let (transport, client) = client(pool);
+
+    client.send(get_company_profile()).await.unwrap();
+    client.send(get_company_profile()).await.unwrap();
+    let Err(CompaniesHousePublicDataOperationError::ApiKeyPoolExhausted(ApiKeyPoolExhausted {
+        retry_after: Some(retry_after),
+    })) = client.send(get_company_profile()).await
+    else {
+        panic!("expected the pool to be exhausted");
+    };
+
+    assert!(retry_after <= Duration::from_secs(3600));
+    assert_eq!(transport.keys(), ["a", "b"]);
+}
+
+#[tokio::test]
+async fn least_used_skips_keys_which_are_too_many_requests() {
+    let pool = ApiKeyPool::new(["a", "b"])
+        .unwrap()
+        .selection(ApiKeySelection::LeastUsed)
+        .rate_limit(10, Duration::from_secs(3600));
+    let (transport, client) = client(pool);
+    transport.fail_next("a", StatusCode::TOO_MANY_REQUESTS);
+
+    client.send(get_company_profile()).await.unwrap_err();
+    for _ in 0..3 {
+        client.send(get_company_profile()).await.unwrap();
+    }
+
+    assert_eq!(transport.keys(), ["a", "b", "b", "b"]);
+    let window_requests: Vec<u32> = (client.api_key_pool().stats().iter())
+        .map(|stats| stats.window_requests)
+        .collect();
+    assert_eq!(window_requests, [10, 3]);
+}
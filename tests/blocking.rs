@@ -0,0 +1,90 @@
+use companies_house_api::{
+    blocking::{CompaniesHousePublicDataClient, CompaniesHouseStreamingClient},
+    operation::get_company_profile::GetCompanyProfile,
+    streaming::{operation::filings::StreamFilings, CompaniesHouseStreamingNextError},
+    test_util::{
+        fixtures, MockPublicDataServer, MockStreamConnection, MockStreamStep, MockStreamingServer,
+    },
+    types::CompanyNumber,
+};
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+const API_KEY: &str = "blocking-key";
+
+#[test]
+fn sends_operations() {
+    // The mock server runs on its own runtime, as the blocking client owns another.
+    let runtime = Runtime::new().unwrap();
+    let server = runtime
+        .block_on(MockPublicDataServer::start(API_KEY))
+        .unwrap();
+    let client = CompaniesHousePublicDataClient::new(API_KEY)
+        .unwrap()
+        .base_url(&server.base_url())
+        .unwrap();
+
+    let profile = client
+        .send(
+            GetCompanyProfile::builder()
+                .company_number(fixtures::COMPANY_NUMBER.parse::<CompanyNumber>().unwrap())
+                .build(),
+        )
+        .unwrap();
+
+    assert_eq!(profile.company_number, fixtures::COMPANY_NUMBER);
+}
+
+#[test]
+fn iterates_stream_items_until_complete() {
+    let runtime = Runtime::new().unwrap();
+    let server = runtime
+        .block_on(MockStreamingServer::start(API_KEY))
+        .unwrap();
+    for timepoint in 1..=3 {
+        server.push_item("/filings", fixtures::filing(timepoint));
+    }
+    server.push_connection(MockStreamConnection::Steps(vec![
+        MockStreamStep::Items(3),
+        MockStreamStep::End,
+    ]));
+    let client = CompaniesHouseStreamingClient::new(API_KEY)
+        .unwrap()
+        .base_url(server.base_url())
+        .chunk_timeout(Duration::from_secs(5));
+
+    let timepoints = client
+        .stream(StreamFilings, None)
+        .unwrap()
+        .map(|item| item.map(|item| item.event.timepoint))
+        .collect::<Result<Vec<_>, CompaniesHouseStreamingNextError>>()
+        .unwrap();
+
+    assert_eq!(timepoints, vec![1, 2, 3]);
+}
+
+#[test]
+fn stops_iterating_after_connection_error() {
+    let runtime = Runtime::new().unwrap();
+    let server = runtime
+        .block_on(MockStreamingServer::start(API_KEY))
+        .unwrap();
+    server.push_item("/filings", fixtures::filing(1));
+    server.push_connection(MockStreamConnection::Steps(vec![
+        MockStreamStep::Items(1),
+        MockStreamStep::Disconnect,
+    ]));
+    let client = CompaniesHouseStreamingClient::new(API_KEY)
+        .unwrap()
+        .base_url(server.base_url())
+        .chunk_timeout(Duration::from_secs(5));
+
+    let results: Vec<_> = client.stream(StreamFilings, None).unwrap().collect();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].as_ref().unwrap().event.timepoint, 1);
+    assert!(matches!(
+        results[1],
+        Err(CompaniesHouseStreamingNextError::BadChunk(_))
+    ));
+}
@@ -0,0 +1,81 @@
+use companies_house_api::types::{
+    CompanyNumber, CompanyNumberJurisdiction, CompanyNumberKind, InvalidCompanyNumber,
+};
+
+#[test]
+fn normalises_case_and_padding() {
+    for (input, expected) in [
+        ("01234567", "01234567"),
+        ("1234567", "01234567"),
+        (" sc123 ", "SC000123"),
+        ("oc301234", "OC301234"),
+        ("R12345", "R0012345"),
+        ("ip123r", "IP00123R"),
+    ] {
+        assert_eq!(CompanyNumber::new(input).unwrap().as_str(), expected);
+    }
+}
+
+#[test]
+fn rejects_malformed_numbers() {
+    for input in [
+        "",
+        "SC",
+        "ABC12345",
+        "123456789",
+        "SC1234567",
+        "12-34567",
+        "../1234",
+    ] {
+        assert_eq!(
+            input.parse::<CompanyNumber>(),
+            Err(InvalidCompanyNumber {
+                value: input.to_owned()
+            })
+        );
+    }
+}
+
+#[test]
+fn classifies_prefixes() {
+    for (input, jurisdiction, kind) in [
+        (
+            "01234567",
+            CompanyNumberJurisdiction::EnglandWales,
+            CompanyNumberKind::Company,
+        ),
+        (
+            "SC123456",
+            CompanyNumberJurisdiction::Scotland,
+            CompanyNumberKind::Company,
+        ),
+        (
+            "NI123456",
+            CompanyNumberJurisdiction::NorthernIreland,
+            CompanyNumberKind::Company,
+        ),
+        (
+            "OC123456",
+            CompanyNumberJurisdiction::EnglandWales,
+            CompanyNumberKind::LimitedLiabilityPartnership,
+        ),
+        (
+            "SL012345",
+            CompanyNumberJurisdiction::Scotland,
+            CompanyNumberKind::LimitedPartnership,
+        ),
+        (
+            "OE012345",
+            CompanyNumberJurisdiction::UnitedKingdom,
+            CompanyNumberKind::OverseasEntity,
+        ),
+    ] {
+        let company_number = CompanyNumber::new(input).unwrap();
+        assert_eq!(company_number.jurisdiction(), Some(jurisdiction));
+        assert_eq!(company_number.kind(), Some(kind));
+    }
+
+    let unrecognised = CompanyNumber::new("XX123456").unwrap();
+    assert_eq!(unrecognised.prefix(), Some("XX"));
+    assert_eq!(unrecognised.kind(), None);
+}
@@ -0,0 +1,69 @@
+use companies_house_api::types::{Date, Timestamp};
+
+#[test]
+fn parses_and_formats_dates() {
+    let date: Date = "2024-02-29".parse().unwrap();
+    assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 29));
+    assert_eq!(date.to_string(), "2024-02-29");
+    assert_eq!(serde_json::to_string(&date).unwrap(), r#""2024-02-29""#);
+}
+
+#[test]
+fn rejects_invalid_dates() {
+    for value in [
+        "2023-02-29",
+        "2024-13-01",
+        "2024-1-01",
+        "2024-01-01T00:00:00",
+        "24-01-01",
+        "2024-01-+1",
+        "2024é1-01",
+        "20é4-01-01",
+        "2024-01-0é",
+        "",
+    ] {
+        let err = value.parse::<Date>().unwrap_err();
+        assert_eq!(err.value, value);
+    }
+    let err = serde_json::from_str::<Date>(r#""01/02/2024""#).unwrap_err();
+    assert!(err.to_string().contains("Invalid ISO-8601 date"));
+}
+
+#[test]
+fn parses_and_formats_timestamps() {
+    let timestamp: Timestamp = "2024-11-27T12:34:56".parse().unwrap();
+    assert_eq!(timestamp.to_string(), "2024-11-27T12:34:56");
+    assert_eq!(timestamp.unix_seconds(), 1_732_710_896);
+
+    let timestamp: Timestamp = "1970-01-01T00:00:01.25".parse().unwrap();
+    assert_eq!(timestamp.nanosecond(), 250_000_000);
+    assert_eq!(timestamp.unix_seconds(), 1);
+    assert_eq!(timestamp.to_string(), "1970-01-01T00:00:01.25");
+
+    for value in [
+        "2024-11-27",
+        "2024-11-27T24:00:00",
+        "2024-11-27T12:34:56.",
+        "2024-01-01T1é:00:0",
+        "2024-01-01T12:3é:0",
+        "2024-01-01T12:34:56.é",
+    ] {
+        assert!(value.parse::<Timestamp>().is_err(), "{value}");
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn converts_to_chrono() {
+    let timestamp: Timestamp = "2024-11-27T12:34:56".parse().unwrap();
+    let date_time = chrono::NaiveDateTime::from(timestamp);
+    assert_eq!(date_time.to_string(), "2024-11-27 12:34:56");
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn converts_to_time() {
+    let timestamp: Timestamp = "2024-11-27T12:34:56".parse().unwrap();
+    let date_time = time::PrimitiveDateTime::from(timestamp);
+    assert_eq!(date_time.to_string(), "2024-11-27 12:34:56.0");
+}
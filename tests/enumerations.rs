@@ -0,0 +1,62 @@
+use companies_house_api::{
+    enumerations::format_filing_history_description,
+    types::{
+        CompanyProfileCompanyStatus, CompanyProfileJurisdiction, CompanyStatusItemCompanyType,
+        FilingHistory,
+    },
+};
+use serde_json::json;
+
+#[test]
+fn describes_enum_values() {
+    assert_eq!(
+        CompanyProfileCompanyStatus::Receivership.description(),
+        Some("Receiver Action")
+    );
+    assert_eq!(
+        CompanyProfileJurisdiction::NonEu.description(),
+        Some("Foreign (Non E.U.)")
+    );
+    assert_eq!(
+        CompanyStatusItemCompanyType::Ltd.description(),
+        Some("Private limited company")
+    );
+    assert_eq!(
+        CompanyProfileCompanyStatus::from("not-a-status").description(),
+        None
+    );
+}
+
+#[test]
+fn formats_filing_history_descriptions() {
+    let filing: FilingHistory = serde_json::from_value(json!({
+        "category": "address",
+        "date": "2024-01-02",
+        "description": "change-registered-office-address-company-with-date-old-address-new-address",
+        "description_values": {
+            "change_date": "2024-01-01",
+            "new_address": "1 High Street, London, EC1A 1BB",
+            "old_address": "20 Station Road, Glasgow, G1 1AA"
+        },
+        "transaction_id": "MzQ1",
+        "type": "AD01"
+    }))
+    .unwrap();
+
+    assert_eq!(
+        filing.formatted_description().unwrap(),
+        "Registered office address changed from 20 Station Road, Glasgow, G1 1AA to 1 High Street, London, EC1A 1BB on 1 January 2024"
+    );
+}
+
+#[test]
+fn leaves_missing_values_in_place() {
+    assert_eq!(
+        format_filing_history_description("confirmation-statement-with-no-updates", None).unwrap(),
+        "Confirmation statement made on {made_up_date} with no updates"
+    );
+    assert_eq!(
+        format_filing_history_description("not-a-description", None),
+        None
+    );
+}
@@ -0,0 +1,40 @@
+use companies_house_api::types::{
+    CompanyProfile, CompanyProfileCompanyStatus, CompanyProfileType, Country, FilingHistoryCategory,
+};
+use serde_json::json;
+
+#[test]
+fn known_values_parse_to_variants() {
+    let status: CompanyProfileCompanyStatus = serde_json::from_str(r#""active""#).unwrap();
+    assert_eq!(status, CompanyProfileCompanyStatus::Active);
+
+    let country: Country = serde_json::from_str(r#""Northern Ireland""#).unwrap();
+    assert_eq!(country, Country::NorthernIreland);
+    assert_eq!(country.to_string(), "Northern Ireland");
+}
+
+#[test]
+fn unknown_values_round_trip() {
+    let category: FilingHistoryCategory = serde_json::from_str(r#""gazette""#).unwrap();
+    assert_eq!(
+        category,
+        FilingHistoryCategory::Unknown("gazette".to_owned())
+    );
+    assert!(category.is_unknown());
+    assert_eq!(serde_json::to_string(&category).unwrap(), r#""gazette""#);
+}
+
+#[test]
+fn new_values_do_not_fail_a_profile() {
+    let profile: CompanyProfile = serde_json::from_value(json!({
+        "company_name": "EXAMPLE TRADING LIMITED",
+        "company_number": "01234567",
+        "company_status": "some-new-status",
+        "links": {},
+        "type": "some-new-type"
+    }))
+    .unwrap();
+
+    assert_eq!(profile.r#type, CompanyProfileType::from("some-new-type"));
+    assert_eq!(profile.company_status.unwrap().as_str(), "some-new-status");
+}
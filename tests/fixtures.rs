@@ -0,0 +1,157 @@
+use companies_house_api::{
+    streaming::{
+        operation::{
+            filings::{FilingHistory, StreamFilings},
+            StreamItem,
+        },
+        ReplayStream,
+    },
+    test_util::fixtures,
+    types::{
+        ChargeList, CompanyInsolvency, CompanyProfile, CompanyProfileForeignCompanyDetailsAccounts,
+        CompanyRegister, CompanySearch, DisqualifiedOfficerSearch, FilingHistoryList, OfficerList,
+        OfficerSearch, PersonsWithSignificantControlList, RegisteredOfficeAddress,
+    },
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::io::Cursor;
+
+/// Drop `null` members, which are serialized for absent optional fields.
+fn without_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(object) => Value::Object(
+            object
+                .into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key, without_nulls(value)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(without_nulls).collect()),
+        value => value,
+    }
+}
+
+/// Every field in the fixture is parsed into the type and serialized back, and
+/// parsing the serialized value gives an equal value.
+fn assert_round_trips<T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug>(
+    fixture: &str,
+) {
+    let original: Value = serde_json::from_str(fixture).unwrap();
+    let parsed: T = serde_json::from_str(fixture).unwrap();
+    let serialized = serde_json::to_value(&parsed).unwrap();
+
+    assert_eq!(without_nulls(serialized.clone()), original);
+    assert_eq!(serde_json::from_value::<T>(serialized).unwrap(), parsed);
+}
+
+#[test]
+fn company_profile_round_trips() {
+    assert_round_trips::<CompanyProfile>(fixtures::COMPANY_PROFILE);
+}
+
+#[test]
+fn registered_office_address_round_trips() {
+    assert_round_trips::<RegisteredOfficeAddress>(fixtures::REGISTERED_OFFICE_ADDRESS);
+}
+
+#[test]
+fn officer_list_round_trips() {
+    assert_round_trips::<OfficerList>(fixtures::OFFICER_LIST);
+}
+
+#[test]
+fn filing_history_list_round_trips() {
+    assert_round_trips::<FilingHistoryList>(fixtures::FILING_HISTORY_LIST);
+}
+
+#[test]
+fn charge_list_round_trips() {
+    assert_round_trips::<ChargeList>(fixtures::CHARGE_LIST);
+}
+
+#[test]
+fn persons_with_significant_control_list_round_trips() {
+    assert_round_trips::<PersonsWithSignificantControlList>(
+        fixtures::PERSONS_WITH_SIGNIFICANT_CONTROL_LIST,
+    );
+}
+
+#[test]
+fn company_insolvency_round_trips() {
+    assert_round_trips::<CompanyInsolvency>(fixtures::COMPANY_INSOLVENCY);
+}
+
+#[test]
+fn company_registers_round_trips() {
+    assert_round_trips::<CompanyRegister>(fixtures::COMPANY_REGISTERS);
+}
+
+#[test]
+fn company_search_round_trips() {
+    assert_round_trips::<CompanySearch>(fixtures::COMPANY_SEARCH);
+}
+
+#[test]
+fn officer_search_round_trips() {
+    assert_round_trips::<OfficerSearch>(fixtures::OFFICER_SEARCH);
+}
+
+#[test]
+fn disqualified_officer_search_round_trips() {
+    assert_round_trips::<DisqualifiedOfficerSearch>(fixtures::DISQUALIFIED_OFFICER_SEARCH);
+}
+
+#[test]
+fn filings_stream_round_trips() {
+    for line in fixtures::FILINGS_STREAM.lines() {
+        assert_round_trips::<StreamItem<FilingHistory>>(line);
+    }
+}
+
+#[tokio::test]
+async fn replayed_items_match_parsed_items() {
+    let mut replay = ReplayStream::new(StreamFilings, Cursor::new(fixtures::FILINGS_STREAM));
+
+    for line in fixtures::FILINGS_STREAM.lines() {
+        let replayed = replay.next().await.unwrap();
+        assert_eq!(replayed, serde_json::from_str(line).unwrap());
+        assert_eq!(replayed.clone(), replayed);
+    }
+}
+
+#[test]
+fn next_accounts_are_parsed() {
+    let profile: CompanyProfile = serde_json::from_str(fixtures::COMPANY_PROFILE).unwrap();
+
+    let next_accounts = profile.accounts.unwrap().next_accounts.unwrap();
+    assert_eq!(next_accounts.due_on.unwrap().to_string(), "2025-09-30");
+}
+
+#[test]
+fn numeric_strings_round_trip_as_strings() {
+    let fixture = r#"{"account_period_from":{"month":"4"},"must_file_within":{"months":"3"}}"#;
+
+    let accounts: CompanyProfileForeignCompanyDetailsAccounts =
+        serde_json::from_str(fixture).unwrap();
+
+    assert_eq!(accounts.account_period_from.as_ref().unwrap().day, None);
+    assert_eq!(
+        accounts.account_period_from.as_ref().unwrap().month,
+        Some(4)
+    );
+    assert_eq!(
+        without_nulls(serde_json::to_value(&accounts).unwrap()),
+        serde_json::from_str::<Value>(fixture).unwrap()
+    );
+}
+
+#[cfg(feature = "strict")]
+#[test]
+fn strict_rejects_unknown_fields() {
+    let mut fixture: Value = serde_json::from_str(fixtures::REGISTERED_OFFICE_ADDRESS).unwrap();
+    fixture["new_field"] = true.into();
+
+    let err = serde_json::from_value::<RegisteredOfficeAddress>(fixture).unwrap_err();
+    assert!(err.to_string().contains("unknown field `new_field`"));
+}
@@ -0,0 +1,76 @@
+use companies_house_api::streaming::{CompaniesHouseStreamingNextError, LineFramer};
+
+fn next_line(framer: &mut LineFramer) -> Result<Option<String>, CompaniesHouseStreamingNextError> {
+    framer
+        .next_line()
+        .map(|line| line.map(|line| String::from_utf8(line.to_vec()).unwrap()))
+}
+
+fn is_line_too_long(result: Result<Option<String>, CompaniesHouseStreamingNextError>) -> bool {
+    matches!(
+        result,
+        Err(CompaniesHouseStreamingNextError::LineTooLong { max_line_length: 8 })
+    )
+}
+
+#[test]
+fn joins_lines_split_mid_line() {
+    let mut framer = LineFramer::new(64);
+    framer.push(b"{\"a\":");
+    assert_eq!(next_line(&mut framer).unwrap(), None);
+    framer.push(b"1}\n{\"b\"");
+    assert_eq!(
+        next_line(&mut framer).unwrap().as_deref(),
+        Some("{\"a\":1}\n")
+    );
+    assert_eq!(next_line(&mut framer).unwrap(), None);
+    framer.push(b":2}\n");
+    assert_eq!(
+        next_line(&mut framer).unwrap().as_deref(),
+        Some("{\"b\":2}\n")
+    );
+    assert_eq!(next_line(&mut framer).unwrap(), None);
+    assert!(framer.is_empty());
+}
+
+#[test]
+fn limit_excludes_newline() {
+    let mut framer = LineFramer::new(8);
+    framer.push(b"12345678");
+    assert_eq!(next_line(&mut framer).unwrap(), None);
+    framer.push(b"\n");
+    assert_eq!(
+        next_line(&mut framer).unwrap().as_deref(),
+        Some("12345678\n")
+    );
+
+    framer.push(b"123456789\n");
+    assert!(is_line_too_long(next_line(&mut framer)));
+    assert!(framer.is_empty());
+
+    framer.push(b"123456789");
+    assert!(is_line_too_long(next_line(&mut framer)));
+}
+
+#[test]
+fn resyncs_after_oversized_line() {
+    let mut framer = LineFramer::new(8);
+    framer.push(b"{\"abcdefghij");
+    assert!(is_line_too_long(next_line(&mut framer)));
+    assert!(!framer.is_empty());
+
+    framer.push(b"klm");
+    assert_eq!(next_line(&mut framer).unwrap(), None);
+    framer.push(b"\"}\n{}\n");
+    assert_eq!(next_line(&mut framer).unwrap().as_deref(), Some("{}\n"));
+    assert_eq!(next_line(&mut framer).unwrap(), None);
+    assert!(framer.is_empty());
+}
+
+#[test]
+fn keeps_lines_after_oversized_complete_line() {
+    let mut framer = LineFramer::new(8);
+    framer.push(b"{\"abcdefghij\"}\n{}\n");
+    assert!(is_line_too_long(next_line(&mut framer)));
+    assert_eq!(next_line(&mut framer).unwrap().as_deref(), Some("{}\n"));
+}
@@ -0,0 +1,148 @@
+use companies_house_api::{
+    operation::get_company_profile::GetCompanyProfile,
+    streaming::{operation::filings::StreamFilings, CompaniesHouseStreamingClient},
+    telemetry,
+    test_util::{fixtures, MockStreamConnection, MockStreamStep, MockStreamingServer},
+    transport::{HttpRequest, HttpResponse, HttpTransport, HttpTransportFuture},
+    types::CompanyNumber,
+    ApiKeyPool, CompaniesHousePublicDataClient,
+};
+use metrics_util::debugging::{DebugValue, DebuggingRecorder, Snapshot};
+use reqwest::{
+    header::{self, HeaderMap, HeaderValue},
+    StatusCode,
+};
+
+const API_KEY: &str = "metrics-key";
+const OTHER_API_KEY: &str = "other-metrics-key";
+
+/// Reports a different remaining rate limit for each key.
+struct RateLimitedTransport;
+
+impl HttpTransport for RateLimitedTransport {
+    fn execute(&self, request: HttpRequest) -> HttpTransportFuture<'_> {
+        let remaining = match request.headers[header::AUTHORIZATION].to_str().unwrap() {
+            API_KEY => "599",
+            _ => "42",
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remain", HeaderValue::from_static(remaining));
+        Box::pin(async move {
+            Ok(HttpResponse {
+                status: StatusCode::OK,
+                headers,
+                body: fixtures::COMPANY_PROFILE.into(),
+            })
+        })
+    }
+}
+
+/// Metric name, labels and value.
+type Metric = (String, Vec<(String, String)>, DebugValue);
+
+fn metrics(snapshot: Snapshot) -> Vec<Metric> {
+    snapshot
+        .into_vec()
+        .into_iter()
+        .map(|(key, _, _, value)| {
+            let key = key.key();
+            let labels = key
+                .labels()
+                .map(|label| (label.key().to_owned(), label.value().to_owned()))
+                .collect();
+            (key.name().to_owned(), labels, value)
+        })
+        .collect()
+}
+
+fn value<'a>(metrics: &'a [Metric], name: &str, labels: &[(&str, &str)]) -> Option<&'a DebugValue> {
+    metrics
+        .iter()
+        .find(|(metric, metric_labels, _)| {
+            metric == name
+                && labels.iter().all(|(label, value)| {
+                    metric_labels.contains(&(label.to_string(), value.to_string()))
+                })
+        })
+        .map(|(.., value)| value)
+}
+
+// A single test, as the recorder is global and snapshots drain its values.
+#[tokio::test]
+async fn records_request_and_stream_metrics() {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    recorder.install().unwrap();
+
+    let client = CompaniesHousePublicDataClient::with_api_key_pool(
+        ApiKeyPool::new([API_KEY, OTHER_API_KEY]).unwrap(),
+    )
+    .unwrap()
+    .transport(RateLimitedTransport);
+    for _ in 0..2 {
+        client
+            .send(
+                GetCompanyProfile::builder()
+                    .company_number(fixtures::COMPANY_NUMBER.parse::<CompanyNumber>().unwrap())
+                    .build(),
+            )
+            .await
+            .unwrap();
+    }
+
+    let server = MockStreamingServer::start(API_KEY).await.unwrap();
+    server.push_item("/filings", fixtures::filing(1));
+    server.push_item("/filings", fixtures::filing(2));
+    server.push_connection(MockStreamConnection::Steps(vec![
+        MockStreamStep::Items(1),
+        MockStreamStep::Heartbeat,
+        MockStreamStep::Items(1),
+        MockStreamStep::End,
+    ]));
+    let streaming_client = CompaniesHouseStreamingClient::new(API_KEY).base_url(server.base_url());
+    let mut connection = streaming_client.stream(StreamFilings, None).await.unwrap();
+    connection.next().await.unwrap();
+    connection.next().await.unwrap();
+
+    let snapshot = metrics(snapshotter.snapshot());
+    let request = [("operation", "GetCompanyProfile"), ("status", "2xx")];
+    assert_eq!(
+        value(&snapshot, telemetry::REQUESTS_TOTAL, &request),
+        Some(&DebugValue::Counter(2))
+    );
+    assert!(matches!(
+        value(&snapshot, telemetry::REQUEST_DURATION_SECONDS, &request),
+        Some(DebugValue::Histogram(values)) if values.len() == 2
+    ));
+    assert_eq!(
+        value(
+            &snapshot,
+            telemetry::RATE_LIMIT_REMAINING,
+            &[("operation", "GetCompanyProfile"), ("api_key", "0")]
+        ),
+        Some(&DebugValue::Gauge(599.0.into()))
+    );
+    assert_eq!(
+        value(
+            &snapshot,
+            telemetry::RATE_LIMIT_REMAINING,
+            &[("operation", "GetCompanyProfile"), ("api_key", "1")]
+        ),
+        Some(&DebugValue::Gauge(42.0.into()))
+    );
+
+    let endpoint = [("endpoint", "/filings")];
+    assert_eq!(
+        value(&snapshot, telemetry::STREAM_ITEMS_TOTAL, &endpoint),
+        Some(&DebugValue::Counter(2))
+    );
+    assert_eq!(
+        value(&snapshot, telemetry::STREAM_HEARTBEATS_TOTAL, &endpoint),
+        Some(&DebugValue::Counter(1))
+    );
+    let Some(DebugValue::Gauge(lag)) = value(&snapshot, telemetry::STREAM_LAG_SECONDS, &endpoint)
+    else {
+        panic!("expected a stream lag gauge");
+    };
+    assert!(lag.0 > 0.0);
+}
@@ -0,0 +1,186 @@
+use companies_house_api::{
+    streaming::{
+        operation::{
+            companies::StreamCompanies,
+            filings::{FilingHistory, StreamFilings},
+            CompaniesHouseStreamingOperation, StreamItem,
+        },
+        CompaniesHouseStreamingClient, CompaniesHouseStreamingNextError, MultiplexedStreamError,
+        StreamMultiplexer, StreamMultiplexerError, DEFAULT_MAX_CONNECTIONS,
+    },
+    test_util::{
+        fixtures, MockStreamConnection, MockStreamRequest, MockStreamStep, MockStreamingServer,
+    },
+    types::CompanyProfile,
+};
+use std::{collections::HashMap, time::Duration};
+
+const API_KEY: &str = "multiplexer-key";
+
+/// An endpoint without a typed operation, to subscribe to more endpoints than
+/// the crate implements.
+#[derive(Debug, Clone)]
+struct StreamOfficers;
+
+impl CompaniesHouseStreamingOperation for StreamOfficers {
+    const ENDPOINT_PATH: &'static str = "/officers";
+
+    type Data = serde_json::Value;
+}
+
+/// The endpoint and timepoint of an item.
+type Item = (&'static str, usize);
+
+fn company(item: StreamItem<CompanyProfile>) -> Item {
+    ("/companies", item.event.timepoint)
+}
+
+fn filing(item: StreamItem<FilingHistory>) -> Item {
+    ("/filings", item.event.timepoint)
+}
+
+fn officer(item: StreamItem<serde_json::Value>) -> Item {
+    ("/officers", item.event.timepoint)
+}
+
+fn multiplexer_for(server: &MockStreamingServer) -> StreamMultiplexer<Item> {
+    let client = CompaniesHouseStreamingClient::new(API_KEY)
+        .base_url(server.base_url())
+        .chunk_timeout(Duration::from_secs(5));
+    StreamMultiplexer::new(client).reconnect_delay(Duration::from_millis(10))
+}
+
+#[tokio::test]
+async fn merges_items_tagged_by_endpoint() {
+    let server = MockStreamingServer::start(API_KEY).await.unwrap();
+    for timepoint in 1..=2 {
+        server.push_item("/companies", fixtures::company(timepoint));
+    }
+    for timepoint in 11..=13 {
+        server.push_item("/filings", fixtures::filing(timepoint));
+    }
+    let mut multiplexer = multiplexer_for(&server);
+    multiplexer
+        .subscribe(StreamCompanies, None, company)
+        .unwrap();
+    multiplexer.subscribe(StreamFilings, None, filing).unwrap();
+
+    let mut items = Vec::new();
+    for _ in 0..5 {
+        let item = multiplexer.next().await.unwrap();
+        let (endpoint, timepoint) = item.result.unwrap();
+        assert_eq!(item.endpoint, endpoint);
+        items.push((endpoint, timepoint));
+    }
+    items.sort();
+
+    assert_eq!(
+        items,
+        vec![
+            ("/companies", 1),
+            ("/companies", 2),
+            ("/filings", 11),
+            ("/filings", 12),
+            ("/filings", 13),
+        ]
+    );
+    assert_eq!(
+        multiplexer.timepoints(),
+        HashMap::from([("/companies", Some(3)), ("/filings", Some(14))])
+    );
+}
+
+#[tokio::test]
+async fn resumes_from_latest_timepoint_after_disconnect() {
+    let server = MockStreamingServer::start(API_KEY).await.unwrap();
+    for timepoint in 1..=3 {
+        server.push_item("/filings", fixtures::filing(timepoint));
+    }
+    server.push_connection(MockStreamConnection::Steps(vec![
+        MockStreamStep::Items(2),
+        MockStreamStep::Disconnect,
+    ]));
+    let mut multiplexer = multiplexer_for(&server);
+    multiplexer
+        .subscribe(StreamFilings, Some(1), filing)
+        .unwrap();
+
+    let mut next = async || multiplexer.next().await.unwrap().result;
+    assert_eq!(next().await.unwrap(), ("/filings", 1));
+    assert_eq!(next().await.unwrap(), ("/filings", 2));
+    assert!(matches!(
+        next().await,
+        Err(MultiplexedStreamError::Next(
+            CompaniesHouseStreamingNextError::BadChunk(_)
+        ))
+    ));
+    assert_eq!(next().await.unwrap(), ("/filings", 3));
+
+    assert_eq!(
+        server.requests(),
+        vec![
+            MockStreamRequest {
+                path: "/filings".to_owned(),
+                timepoint: Some(1),
+            },
+            MockStreamRequest {
+                path: "/filings".to_owned(),
+                timepoint: Some(3),
+            },
+        ]
+    );
+    assert_eq!(
+        multiplexer.timepoints(),
+        HashMap::from([("/filings", Some(4))])
+    );
+}
+
+#[tokio::test]
+async fn limits_concurrent_connections() {
+    let server = MockStreamingServer::start(API_KEY).await.unwrap();
+
+    let mut multiplexer = multiplexer_for(&server);
+    multiplexer
+        .subscribe(StreamCompanies, None, company)
+        .unwrap();
+    multiplexer.subscribe(StreamFilings, None, filing).unwrap();
+    assert_eq!(DEFAULT_MAX_CONNECTIONS, 2);
+    assert!(matches!(
+        multiplexer.subscribe(StreamOfficers, None, officer),
+        Err(StreamMultiplexerError::TooManyConnections {
+            max_connections: DEFAULT_MAX_CONNECTIONS
+        })
+    ));
+
+    let mut multiplexer = multiplexer_for(&server).max_connections(3);
+    multiplexer
+        .subscribe(StreamCompanies, None, company)
+        .unwrap();
+    multiplexer.subscribe(StreamFilings, None, filing).unwrap();
+    multiplexer
+        .subscribe(StreamOfficers, None, officer)
+        .unwrap();
+}
+
+#[tokio::test]
+async fn rejects_subscribing_to_an_endpoint_twice() {
+    let server = MockStreamingServer::start(API_KEY).await.unwrap();
+
+    let mut multiplexer = multiplexer_for(&server);
+    multiplexer.subscribe(StreamFilings, None, filing).unwrap();
+    assert!(matches!(
+        multiplexer.subscribe(StreamFilings, Some(5), filing),
+        Err(StreamMultiplexerError::AlreadySubscribed("/filings"))
+    ));
+    assert_eq!(
+        multiplexer.timepoints(),
+        HashMap::from([("/filings", None)])
+    );
+}
+
+#[tokio::test]
+async fn has_no_items_without_subscriptions() {
+    let server = MockStreamingServer::start(API_KEY).await.unwrap();
+
+    assert!(multiplexer_for(&server).next().await.is_none());
+}
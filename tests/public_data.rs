@@ -0,0 +1,478 @@
+use companies_house_api::{
+    operation::{
+        endpoint_url,
+        get_company_profile::{GetCompanyProfile, GetCompanyProfileStatusError},
+        get_company_registered_office_address::GetCompanyRegisteredOfficeAddress,
+        list_company_persons_with_significant_control::ListCompanyPersonsWithSignificantControl,
+        search_companies::{SearchCompanies, SearchCompaniesStatusError},
+        search_disqualified_officers::SearchDisqualifiedOfficers,
+        search_officers::SearchOfficers,
+        CompaniesHousePublicDataOperationError, EndpointUrlError,
+    },
+    test_util::{assert_send_sync_clone, fixtures, MockPublicDataServer, MockResponse},
+    types::{
+        ChargeStatus, CompanyNumber, CompanyProfileLinks, FilingHistoryCategory,
+        InsolvencyCaseType, OfficerRole, PersonWithSignificantControlKind, RegisterMovedTo,
+    },
+    CompaniesHousePublicDataClient, CompaniesHousePublicDataClientError,
+};
+use reqwest::StatusCode;
+
+const API_KEY: &str = "public-data-key";
+
+fn company_number(value: &str) -> CompanyNumber {
+    value.parse().unwrap()
+}
+
+async fn server_and_client() -> (MockPublicDataServer, CompaniesHousePublicDataClient) {
+    let server = MockPublicDataServer::start(API_KEY).await.unwrap();
+    let client = CompaniesHousePublicDataClient::new(API_KEY)
+        .unwrap()
+        .base_url(&server.base_url())
+        .unwrap();
+    (server, client)
+}
+
+#[tokio::test]
+async fn get_company_profile() {
+    let (_server, client) = server_and_client().await;
+
+    let profile = client
+        .send(
+            GetCompanyProfile::builder()
+                .company_number(company_number(fixtures::COMPANY_NUMBER))
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(profile.company_number, fixtures::COMPANY_NUMBER);
+    assert_eq!(profile.company_name, "EXAMPLE TRADING LIMITED");
+}
+
+#[tokio::test]
+async fn get_company_registered_office_address() {
+    let (_server, client) = server_and_client().await;
+
+    let address = client
+        .send(
+            GetCompanyRegisteredOfficeAddress::builder()
+                .company_number(company_number(fixtures::COMPANY_NUMBER))
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(address.address_line_1, "1 High Street");
+}
+
+#[tokio::test]
+async fn follows_profile_links() {
+    let (server, client) = server_and_client().await;
+
+    let profile = client
+        .send(
+            GetCompanyProfile::builder()
+                .company_number(company_number(fixtures::COMPANY_NUMBER))
+                .build(),
+        )
+        .await
+        .unwrap();
+    let officers = client
+        .send(profile.links.officers_operation().unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(officers.total_results, 2);
+    assert_eq!(officers.items[0].officer_role, OfficerRole::Director);
+    assert_eq!(server.requests()[1].path, "/company/01234567/officers");
+    assert!(profile.links.self_operation().is_some());
+    assert!(profile.links.insolvency.is_none());
+}
+
+#[tokio::test]
+async fn follows_filing_history_charges_and_psc_links() {
+    let (server, client) = server_and_client().await;
+
+    let profile = client
+        .send(
+            GetCompanyProfile::builder()
+                .company_number(company_number(fixtures::COMPANY_NUMBER))
+                .build(),
+        )
+        .await
+        .unwrap();
+    let filing_history = client
+        .send(profile.links.filing_history_operation().unwrap())
+        .await
+        .unwrap();
+    let charges = client
+        .send(profile.links.charges_operation().unwrap())
+        .await
+        .unwrap();
+    let pscs = client
+        .send(
+            profile
+                .links
+                .persons_with_significant_control_operation()
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(filing_history.total_count, 2);
+    assert_eq!(
+        filing_history.items[0].category,
+        FilingHistoryCategory::Accounts
+    );
+    assert_eq!(charges.items[0].status, ChargeStatus::FullySatisfied);
+    assert_eq!(
+        pscs.items[0].kind,
+        PersonWithSignificantControlKind::IndividualPersonWithSignificantControl
+    );
+    let paths: Vec<_> = server.requests()[1..]
+        .iter()
+        .map(|request| request.path.clone())
+        .collect();
+    assert_eq!(
+        paths,
+        [
+            "/company/01234567/filing-history",
+            "/company/01234567/charges",
+            "/company/01234567/persons-with-significant-control",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn follows_insolvency_and_registers_links() {
+    let (server, client) = server_and_client().await;
+    let links: CompanyProfileLinks = serde_json::from_str(
+        r#"{
+            "insolvency": "/company/01234567/insolvency",
+            "registers": "/company/01234567/registers"
+        }"#,
+    )
+    .unwrap();
+
+    let insolvency = client
+        .send(links.insolvency_operation().unwrap())
+        .await
+        .unwrap();
+    let registers = client
+        .send(links.registers_operation().unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        insolvency.cases[0].r#type,
+        InsolvencyCaseType::CreditorsVoluntaryLiquidation
+    );
+    assert_eq!(
+        registers.registers.directors.unwrap().items[0].register_moved_to,
+        RegisterMovedTo::PublicRegister
+    );
+    assert_eq!(server.requests()[0].path, "/company/01234567/insolvency");
+    assert_eq!(server.requests()[1].path, "/company/01234567/registers");
+}
+
+#[tokio::test]
+async fn sends_register_view() {
+    let (server, client) = server_and_client().await;
+
+    client
+        .send(
+            ListCompanyPersonsWithSignificantControl::builder()
+                .company_number(company_number(fixtures::COMPANY_NUMBER))
+                .register_view(Some(true))
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(server.requests()[0].query["register_view"], "true");
+}
+
+#[test]
+fn missing_links_have_no_operation() {
+    let links: CompanyProfileLinks =
+        serde_json::from_str(r#"{"self": "/company/SC765432"}"#).unwrap();
+
+    assert!(links.self_operation().is_some());
+    assert!(links.officers_operation().is_none());
+    assert!(links.filing_history_operation().is_none());
+    assert!(links.charges_operation().is_none());
+    assert!(links.persons_with_significant_control_operation().is_none());
+    assert!(links.insolvency_operation().is_none());
+    assert!(links.registers_operation().is_none());
+}
+
+#[tokio::test]
+async fn search_companies() {
+    let (server, client) = server_and_client().await;
+
+    let search = client
+        .send(
+            SearchCompanies::builder()
+                .query("example trading")
+                .items_per_page(Some(2))
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(search.items.len(), 2);
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/search/companies");
+    assert_eq!(request.query["q"], "example trading");
+    assert_eq!(request.query["items_per_page"], "2");
+}
+
+#[tokio::test]
+async fn search_officers() {
+    let (_server, client) = server_and_client().await;
+
+    let search = client
+        .send(SearchOfficers::builder().query("example").build())
+        .await
+        .unwrap();
+
+    assert_eq!(search.items.unwrap()[0].appointment_count, 3);
+}
+
+#[tokio::test]
+async fn search_disqualified_officers() {
+    let (_server, client) = server_and_client().await;
+
+    let search = client
+        .send(
+            SearchDisqualifiedOfficers::builder()
+                .query("example")
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(search.items.unwrap()[0].title, "Casey EXAMPLE");
+}
+
+#[tokio::test]
+async fn unknown_company_is_not_found() {
+    let (_server, client) = server_and_client().await;
+
+    let result = client
+        .send(
+            GetCompanyProfile::builder()
+                .company_number(company_number("99999999"))
+                .build(),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(CompaniesHousePublicDataOperationError::Status(
+            GetCompanyProfileStatusError::NotFound
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn bad_api_key_is_unauthorized() {
+    let (server, _client) = server_and_client().await;
+    let client = CompaniesHousePublicDataClient::new("wrong-key")
+        .unwrap()
+        .base_url(&server.base_url())
+        .unwrap();
+
+    let result = client
+        .send(SearchCompanies::builder().query("example").build())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(CompaniesHousePublicDataOperationError::Status(
+            SearchCompaniesStatusError::Unauthorized
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn injected_statuses_are_unexpected() {
+    let (server, client) = server_and_client().await;
+    server.push_response(MockResponse::status(StatusCode::TOO_MANY_REQUESTS));
+    server.push_response(MockResponse::status(StatusCode::INTERNAL_SERVER_ERROR));
+
+    for expected in [
+        StatusCode::TOO_MANY_REQUESTS,
+        StatusCode::INTERNAL_SERVER_ERROR,
+    ] {
+        let result = client
+            .send(SearchCompanies::builder().query("example").build())
+            .await;
+        match result {
+            Err(CompaniesHousePublicDataOperationError::Status(
+                SearchCompaniesStatusError::UnexpectedStatus(err),
+            )) => assert_eq!(err.status_code, expected),
+            other => panic!("expected unexpected status, got {other:?}"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn overridden_route_body_is_parsed() {
+    let (server, client) = server_and_client().await;
+    server.set_response("/company/SC765432", MockResponse::ok("{}"));
+
+    let result = client
+        .send(
+            GetCompanyProfile::builder()
+                .company_number(company_number("sc765432"))
+                .build(),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(CompaniesHousePublicDataOperationError::JsonParse { value: Some(_), .. })
+    ));
+}
+
+#[test]
+fn path_segments_are_percent_encoded() {
+    let base_url = "http://localhost/prefix/".parse().unwrap();
+
+    let url = endpoint_url(&base_url, &["company", "a/b?c d", "officers"]).unwrap();
+
+    assert_eq!(
+        url.as_str(),
+        "http://localhost/prefix/company/a%2Fb%3Fc%20d/officers"
+    );
+}
+
+#[test]
+fn relative_path_segments_are_rejected() {
+    let base_url = "http://localhost".parse().unwrap();
+
+    for segment in ["", ".", ".."] {
+        assert_eq!(
+            endpoint_url(&base_url, &["company", segment]),
+            Err(EndpointUrlError::InvalidPathSegment {
+                segment: segment.to_owned()
+            })
+        );
+    }
+}
+
+#[test]
+fn cannot_be_a_base_url_is_rejected() {
+    let base_url = "mailto:someone@example.com".parse().unwrap();
+
+    assert_eq!(
+        endpoint_url(&base_url, &["company"]),
+        Err(EndpointUrlError::CannotBeABase {
+            base_url: "mailto:someone@example.com".to_owned()
+        })
+    );
+}
+
+#[test]
+fn bad_base_url_is_rejected() {
+    for base_url in ["not a url", "mailto:someone@example.com", "ftp://localhost"] {
+        assert!(matches!(
+            CompaniesHousePublicDataClient::new(API_KEY)
+                .unwrap()
+                .base_url(base_url),
+            Err(CompaniesHousePublicDataClientError::BadBaseUrl { .. })
+        ));
+    }
+}
+
+#[cfg(not(feature = "strict"))]
+#[tokio::test]
+async fn reports_unknown_fields() {
+    use companies_house_api::{UnknownField, UnknownFieldReporter};
+    use std::sync::{Arc, Mutex};
+
+    let (server, client) = server_and_client().await;
+    let unknown = Arc::new(Mutex::new(Vec::new()));
+    let client = client.report_unknown_fields(UnknownFieldReporter::callback({
+        let unknown = unknown.clone();
+        move |field| unknown.lock().unwrap().push(field.clone())
+    }));
+    let mut body: serde_json::Value = serde_json::from_str(fixtures::COMPANY_PROFILE).unwrap();
+    body["new_field"] = true.into();
+    body["accounts"]["next_accounts"]["new_date"] = "2025-01-01".into();
+    server.set_response("/company/01234567", MockResponse::ok(body.to_string()));
+
+    let profile = client
+        .send(
+            GetCompanyProfile::builder()
+                .company_number(company_number(fixtures::COMPANY_NUMBER))
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(profile.company_name, "EXAMPLE TRADING LIMITED");
+    assert_eq!(
+        *unknown.lock().unwrap(),
+        vec![
+            UnknownField {
+                operation: "GetCompanyProfile",
+                path: "accounts.next_accounts.new_date".to_owned(),
+            },
+            UnknownField {
+                operation: "GetCompanyProfile",
+                path: "new_field".to_owned(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn client_is_send_sync_and_clone() {
+    assert_send_sync_clone::<CompaniesHousePublicDataClient>();
+}
+
+#[tokio::test]
+async fn clones_share_api_key_pool() {
+    let (_server, client) = server_and_client().await;
+
+    let tasks: Vec<_> = (0..2)
+        .map(|_| {
+            let client = client.clone();
+            tokio::spawn(async move {
+                client
+                    .send(
+                        GetCompanyProfile::builder()
+                            .company_number(company_number(fixtures::COMPANY_NUMBER))
+                            .build(),
+                    )
+                    .await
+            })
+        })
+        .collect();
+    for task in tasks {
+        task.await.unwrap().unwrap();
+    }
+
+    assert_eq!(client.api_key_pool().stats()[0].requests, 2);
+}
+
+#[tokio::test]
+async fn sends_with_configured_http_client() {
+    let (_server, client) = server_and_client().await;
+    let client = client.http_client(reqwest::Client::builder().https_only(true).build().unwrap());
+
+    let result = client
+        .send(
+            GetCompanyProfile::builder()
+                .company_number(company_number(fixtures::COMPANY_NUMBER))
+                .build(),
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(CompaniesHousePublicDataOperationError::Transport(_))
+    ));
+}
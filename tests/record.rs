@@ -0,0 +1,135 @@
+use companies_house_api::{
+    streaming::{
+        operation::filings::StreamFilings, CompaniesHouseStreamingClient,
+        CompaniesHouseStreamingNextError, ReplayStream, StreamRecorder,
+    },
+    test_util::{fixtures, MockStreamConnection, MockStreamStep, MockStreamingServer},
+};
+use std::{fs, io::Cursor, path::PathBuf, time::Duration};
+
+const API_KEY: &str = "record-key";
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("companies-house-{}-{name}", std::process::id()))
+}
+
+/// Stream filings 1 to 3 from a mock server into `recorder`.
+async fn record(recorder: StreamRecorder) -> StreamRecorder {
+    let server = MockStreamingServer::start(API_KEY).await.unwrap();
+    for timepoint in 1..=3 {
+        server.push_item("/filings", fixtures::filing(timepoint));
+    }
+    server.push_connection(MockStreamConnection::Steps(vec![
+        MockStreamStep::Items(3),
+        MockStreamStep::End,
+    ]));
+    let mut stream = CompaniesHouseStreamingClient::new(API_KEY)
+        .base_url(server.base_url())
+        .chunk_timeout(Duration::from_secs(5))
+        .stream(StreamFilings, None)
+        .await
+        .unwrap()
+        .with_recorder(recorder);
+
+    for timepoint in 1..=3 {
+        assert_eq!(stream.next().await.unwrap().event.timepoint, timepoint);
+    }
+    stream.take_recorder().unwrap()
+}
+
+async fn replayed_timepoints(mut replay: ReplayStream<StreamFilings>) -> Vec<usize> {
+    let mut timepoints = Vec::new();
+    loop {
+        match replay.next().await {
+            Ok(item) => timepoints.push(item.event.timepoint),
+            Err(CompaniesHouseStreamingNextError::StreamComplete) => return timepoints,
+            Err(err) => panic!("{err}"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn records_one_item_per_line() {
+    let path = temp_path("record.ndjson");
+    record(StreamRecorder::create(&path).unwrap())
+        .await
+        .finish()
+        .unwrap();
+
+    let recording = fs::read_to_string(&path).unwrap();
+    let lines: Vec<serde_json::Value> = recording
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines, (1..=3).map(fixtures::filing).collect::<Vec<_>>());
+
+    let replay = ReplayStream::open(StreamFilings, &path).unwrap();
+    assert_eq!(replayed_timepoints(replay).await, vec![1, 2, 3]);
+    fs::remove_file(&path).unwrap();
+}
+
+#[cfg(feature = "gzip")]
+#[tokio::test]
+async fn gzip_recording_round_trips() {
+    use std::io::Read;
+
+    let path = temp_path("record.ndjson.gz");
+    record(StreamRecorder::create_gzip(&path).unwrap())
+        .await
+        .finish()
+        .unwrap();
+
+    let mut magic = [0; 2];
+    fs::File::open(&path)
+        .unwrap()
+        .read_exact(&mut magic)
+        .unwrap();
+    assert_eq!(magic, [0x1f, 0x8b]);
+
+    let replay = ReplayStream::open(StreamFilings, &path).unwrap();
+    assert_eq!(replayed_timepoints(replay).await, vec![1, 2, 3]);
+    fs::remove_file(&path).unwrap();
+}
+
+#[cfg(not(feature = "gzip"))]
+#[test]
+fn gzip_recordings_need_the_gzip_feature() {
+    let path = temp_path("unsupported.ndjson.gz");
+    fs::write(&path, [0x1f, 0x8b, 0x08]).unwrap();
+
+    let err = ReplayStream::open(StreamFilings, &path).err().unwrap();
+    assert_eq!(err.kind(), std::io::ErrorKind::Unsupported);
+    fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test(start_paused = true)]
+async fn real_time_replay_waits_between_items() {
+    // Timepoints 1, 2 and 5 are published one and three seconds apart.
+    let recording: String = [1, 2, 5]
+        .into_iter()
+        .map(|timepoint| format!("{}\n", fixtures::filing(timepoint)))
+        .collect();
+    let mut replay = ReplayStream::new(StreamFilings, Cursor::new(recording)).real_time(true);
+
+    let start = tokio::time::Instant::now();
+    replay.next().await.unwrap();
+    assert_eq!(start.elapsed(), Duration::ZERO);
+    replay.next().await.unwrap();
+    assert_eq!(start.elapsed(), Duration::from_secs(1));
+    replay.next().await.unwrap();
+    assert_eq!(start.elapsed(), Duration::from_secs(4));
+}
+
+#[tokio::test(start_paused = true)]
+async fn replay_is_immediate_by_default() {
+    let recording: String = [1, 60]
+        .into_iter()
+        .map(|timepoint| format!("{}\n", fixtures::filing(timepoint)))
+        .collect();
+    let mut replay = ReplayStream::new(StreamFilings, Cursor::new(recording));
+
+    let start = tokio::time::Instant::now();
+    replay.next().await.unwrap();
+    replay.next().await.unwrap();
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
@@ -0,0 +1,271 @@
+use companies_house_api::{
+    streaming::{
+        operation::filings::StreamFilings, CompaniesHouseStreamingClient,
+        CompaniesHouseStreamingConnectionError, CompaniesHouseStreamingNextError,
+    },
+    test_util::{
+        assert_send_sync_clone, fixtures, MockStreamConnection, MockStreamRequest, MockStreamStep,
+        MockStreamingServer,
+    },
+    UnknownField, UnknownFieldReporter,
+};
+use reqwest::StatusCode;
+use serde_json::json;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+const API_KEY: &str = "streaming-key";
+
+async fn server_with_filings(timepoints: impl IntoIterator<Item = usize>) -> MockStreamingServer {
+    let server = MockStreamingServer::start(API_KEY).await.unwrap();
+    for timepoint in timepoints {
+        server.push_item("/filings", fixtures::filing(timepoint));
+    }
+    server
+}
+
+fn client(server: &MockStreamingServer) -> CompaniesHouseStreamingClient {
+    CompaniesHouseStreamingClient::new(API_KEY)
+        .base_url(server.base_url())
+        .connection_timeout(Duration::from_secs(5))
+        .chunk_timeout(Duration::from_secs(5))
+}
+
+#[tokio::test]
+async fn streams_items_from_timepoint() {
+    let server = server_with_filings(1..=5).await;
+    let mut stream = client(&server)
+        .stream(StreamFilings, Some(3))
+        .await
+        .unwrap();
+
+    for timepoint in 3..=5 {
+        let item = stream.next().await.unwrap();
+        assert_eq!(item.event.timepoint, timepoint);
+        assert_eq!(item.data.transaction_id, format!("MzQ1{timepoint}"));
+    }
+
+    assert_eq!(
+        server.requests(),
+        vec![MockStreamRequest {
+            path: "/filings".to_owned(),
+            timepoint: Some(3),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn skips_heartbeats_between_items() {
+    let server = server_with_filings(1..=2).await;
+    server.push_connection(MockStreamConnection::Steps(vec![
+        MockStreamStep::Heartbeat,
+        MockStreamStep::Items(1),
+        MockStreamStep::Heartbeat,
+        MockStreamStep::Heartbeat,
+        MockStreamStep::Items(1),
+        MockStreamStep::End,
+    ]));
+    let mut stream = client(&server).stream(StreamFilings, None).await.unwrap();
+
+    assert_eq!(stream.next().await.unwrap().event.timepoint, 1);
+    assert_eq!(stream.next().await.unwrap().event.timepoint, 2);
+    assert!(matches!(
+        stream.next().await,
+        Err(CompaniesHouseStreamingNextError::StreamComplete)
+    ));
+}
+
+#[tokio::test]
+async fn joins_items_split_across_chunks() {
+    let server = server_with_filings([]).await;
+    let item = format!("{}\n", fixtures::filing(7));
+    let (left, right) = item.as_bytes().split_at(item.len() / 2);
+    server.push_connection(MockStreamConnection::Steps(vec![
+        MockStreamStep::Chunk(left.to_vec()),
+        MockStreamStep::Chunk(right.to_vec()),
+    ]));
+    let mut stream = client(&server).stream(StreamFilings, None).await.unwrap();
+
+    assert_eq!(stream.next().await.unwrap().event.timepoint, 7);
+}
+
+#[tokio::test]
+async fn times_out_between_chunks() {
+    let server = server_with_filings(1..=1).await;
+    server.push_connection(MockStreamConnection::Steps(vec![
+        MockStreamStep::Items(1),
+        MockStreamStep::Sleep(Duration::from_secs(5)),
+    ]));
+    let mut stream = client(&server)
+        .chunk_timeout(Duration::from_millis(200))
+        .stream(StreamFilings, None)
+        .await
+        .unwrap();
+
+    assert_eq!(stream.next().await.unwrap().event.timepoint, 1);
+    assert!(matches!(
+        stream.next().await,
+        Err(CompaniesHouseStreamingNextError::ChunkTimeout)
+    ));
+}
+
+#[tokio::test]
+async fn heartbeats_keep_connection_alive() {
+    let server = server_with_filings([]).await;
+    server.set_heartbeat_interval(Duration::from_millis(50));
+    let mut stream = client(&server)
+        .chunk_timeout(Duration::from_millis(200))
+        .stream(StreamFilings, None)
+        .await
+        .unwrap();
+
+    let next = tokio::time::timeout(Duration::from_millis(600), stream.next()).await;
+    assert!(
+        next.is_err(),
+        "heartbeats should not time out or yield items"
+    );
+}
+
+#[tokio::test]
+async fn reports_abrupt_disconnect() {
+    let server = server_with_filings(1..=1).await;
+    server.push_connection(MockStreamConnection::Steps(vec![
+        MockStreamStep::Items(1),
+        MockStreamStep::Disconnect,
+    ]));
+    let mut stream = client(&server).stream(StreamFilings, None).await.unwrap();
+
+    assert_eq!(stream.next().await.unwrap().event.timepoint, 1);
+    assert!(matches!(
+        stream.next().await,
+        Err(CompaniesHouseStreamingNextError::BadChunk(_))
+    ));
+}
+
+#[tokio::test]
+async fn rejects_old_timepoint() {
+    let server = server_with_filings(10..=12).await;
+    server.set_earliest_timepoint(10);
+
+    let result = client(&server).stream(StreamFilings, Some(2)).await;
+    assert!(matches!(
+        result,
+        Err(CompaniesHouseStreamingConnectionError::BadTimepoint)
+    ));
+}
+
+#[tokio::test]
+async fn rejects_bad_api_key() {
+    let server = server_with_filings([]).await;
+
+    let result = CompaniesHouseStreamingClient::new("wrong-key")
+        .base_url(server.base_url())
+        .stream(StreamFilings, None)
+        .await;
+    assert!(matches!(
+        result,
+        Err(CompaniesHouseStreamingConnectionError::Unauthorized)
+    ));
+}
+
+#[tokio::test]
+async fn reports_rate_limiting() {
+    let server = server_with_filings(1..=1).await;
+    server.push_connection(MockStreamConnection::Status(StatusCode::TOO_MANY_REQUESTS));
+    let client = client(&server);
+
+    assert!(matches!(
+        client.stream(StreamFilings, None).await,
+        Err(CompaniesHouseStreamingConnectionError::TooManyRequests)
+    ));
+    let mut stream = client.stream(StreamFilings, None).await.unwrap();
+    assert_eq!(stream.next().await.unwrap().event.timepoint, 1);
+}
+
+#[tokio::test]
+async fn rejects_lines_over_max_length() {
+    let server = server_with_filings(1..=1).await;
+    let mut stream = client(&server)
+        .max_line_length(64)
+        .stream(StreamFilings, None)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        stream.next().await,
+        Err(CompaniesHouseStreamingNextError::LineTooLong {
+            max_line_length: 64
+        })
+    ));
+}
+
+#[tokio::test]
+async fn reports_unknown_fields() {
+    let server = server_with_filings([]).await;
+    let mut item = fixtures::filing(1);
+    item["data"]["new_field"] = json!("value");
+    server.push_item("/filings", item);
+    let unknown = Arc::new(Mutex::new(Vec::new()));
+    let mut stream = client(&server)
+        .report_unknown_fields(UnknownFieldReporter::callback({
+            let unknown = unknown.clone();
+            move |field| unknown.lock().unwrap().push(field.clone())
+        }))
+        .stream(StreamFilings, None)
+        .await
+        .unwrap();
+
+    assert_eq!(stream.next().await.unwrap().event.timepoint, 1);
+    assert_eq!(
+        *unknown.lock().unwrap(),
+        vec![UnknownField {
+            operation: "StreamFilings",
+            path: "data.new_field".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn client_is_send_sync_and_clone() {
+    assert_send_sync_clone::<CompaniesHouseStreamingClient>();
+}
+
+#[tokio::test]
+async fn clones_stream_concurrently() {
+    let server = server_with_filings(1..=2).await;
+    let client = client(&server);
+
+    let tasks: Vec<_> = [1, 2]
+        .into_iter()
+        .map(|timepoint| {
+            let client = client.clone();
+            tokio::spawn(async move {
+                let mut stream = client.stream(StreamFilings, Some(timepoint)).await?;
+                Ok::<_, CompaniesHouseStreamingConnectionError>(
+                    stream.next().await.unwrap().event.timepoint,
+                )
+            })
+        })
+        .collect();
+    let mut timepoints = Vec::new();
+    for task in tasks {
+        timepoints.push(task.await.unwrap().unwrap());
+    }
+    assert_eq!(timepoints, vec![1, 2]);
+}
+
+#[tokio::test]
+async fn connects_with_configured_http_client() {
+    let server = server_with_filings([]).await;
+
+    let result = client(&server)
+        .http_client(reqwest::Client::builder().https_only(true).build().unwrap())
+        .stream(StreamFilings, None)
+        .await;
+    assert!(matches!(
+        result,
+        Err(CompaniesHouseStreamingConnectionError::UnknownConnection(_))
+    ));
+}
@@ -0,0 +1,90 @@
+use companies_house_api::{
+    operation::{get_company_profile::GetCompanyProfile, CompaniesHousePublicDataOperationError},
+    test_util::{fixtures, MockPublicDataServer},
+    transport::{HttpRequest, HttpResponse, HttpTransport, HttpTransportFuture},
+    types::CompanyNumber,
+    CompaniesHousePublicDataClient,
+};
+use reqwest::{
+    header::{self, HeaderMap, HeaderValue},
+    StatusCode,
+};
+use std::time::Duration;
+use tower::{
+    limit::ConcurrencyLimitLayer, timeout::TimeoutLayer, util::MapRequestLayer, ServiceBuilder,
+    ServiceExt,
+};
+
+const API_KEY: &str = "public-data-key";
+
+fn get_company_profile() -> GetCompanyProfile {
+    GetCompanyProfile::builder()
+        .company_number(fixtures::COMPANY_NUMBER.parse::<CompanyNumber>().unwrap())
+        .build()
+}
+
+struct SlowTransport;
+
+impl HttpTransport for SlowTransport {
+    fn execute(&self, _request: HttpRequest) -> HttpTransportFuture<'_> {
+        Box::pin(async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok(HttpResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: fixtures::COMPANY_PROFILE.into(),
+            })
+        })
+    }
+}
+
+#[tokio::test]
+async fn layers_wrap_the_transport() {
+    let server = MockPublicDataServer::start(API_KEY).await.unwrap();
+    let client = CompaniesHousePublicDataClient::new("stale-key")
+        .unwrap()
+        .base_url(&server.base_url())
+        .unwrap()
+        .layer(MapRequestLayer::new(|mut request: HttpRequest| {
+            request
+                .headers
+                .insert(header::AUTHORIZATION, HeaderValue::from_static(API_KEY));
+            request
+        }))
+        .layer(ConcurrencyLimitLayer::new(1))
+        .layer(TimeoutLayer::new(Duration::from_secs(5)));
+
+    let profile = client.send(get_company_profile()).await.unwrap();
+    assert_eq!(profile.company_number, fixtures::COMPANY_NUMBER);
+}
+
+#[tokio::test]
+async fn timeout_layer_errors_are_transport_errors() {
+    let client = CompaniesHousePublicDataClient::new(API_KEY)
+        .unwrap()
+        .transport(SlowTransport)
+        .layer(TimeoutLayer::new(Duration::from_millis(10)));
+
+    let Err(CompaniesHousePublicDataOperationError::Transport(error)) =
+        client.send(get_company_profile()).await
+    else {
+        panic!("expected a transport error");
+    };
+    assert!(error.into_inner().is::<tower::timeout::error::Elapsed>());
+}
+
+#[tokio::test]
+async fn client_is_a_service_of_operations() {
+    let server = MockPublicDataServer::start(API_KEY).await.unwrap();
+    let client = CompaniesHousePublicDataClient::new(API_KEY)
+        .unwrap()
+        .base_url(&server.base_url())
+        .unwrap();
+
+    let service = ServiceBuilder::new()
+        .concurrency_limit(1)
+        .service(client.into_service());
+
+    let profile = service.oneshot(get_company_profile()).await.unwrap();
+    assert_eq!(profile.company_number, fixtures::COMPANY_NUMBER);
+}
@@ -0,0 +1,109 @@
+use companies_house_api::{
+    operation::get_company_profile::GetCompanyProfile,
+    streaming::{operation::filings::StreamFilings, CompaniesHouseStreamingClient},
+    test_util::{fixtures, MockPublicDataServer, MockStreamingServer},
+    types::CompanyNumber,
+    CompaniesHousePublicDataClient,
+};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, registry::LookupSpan, Layer};
+
+const API_KEY: &str = "tracing-key";
+
+/// Fields recorded on each span, by span name.
+#[derive(Clone, Default)]
+struct SpanFields(Arc<Mutex<HashMap<&'static str, HashMap<String, String>>>>);
+
+impl SpanFields {
+    fn get(&self, span: &str, field: &str) -> Option<String> {
+        self.0.lock().unwrap().get(span)?.get(field).cloned()
+    }
+}
+
+struct Visitor<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for Visitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.insert(
+            field.name().to_owned(),
+            format!("{value:?}").replace('"', ""),
+        );
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for SpanFields {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+        let mut spans = self.0.lock().unwrap();
+        attrs.record(&mut Visitor(
+            spans.entry(attrs.metadata().name()).or_default(),
+        ));
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let name = ctx.span(id).unwrap().name();
+        let mut spans = self.0.lock().unwrap();
+        values.record(&mut Visitor(spans.entry(name).or_default()));
+    }
+}
+
+#[tokio::test]
+async fn send_is_wrapped_in_a_request_span() {
+    let fields = SpanFields::default();
+    let _guard = tracing_subscriber::registry()
+        .with(fields.clone())
+        .set_default();
+
+    let server = MockPublicDataServer::start(API_KEY).await.unwrap();
+    let client = CompaniesHousePublicDataClient::new(API_KEY)
+        .unwrap()
+        .base_url(&server.base_url())
+        .unwrap();
+    client
+        .send(
+            GetCompanyProfile::builder()
+                .company_number(fixtures::COMPANY_NUMBER.parse::<CompanyNumber>().unwrap())
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let field = |name| fields.get("companies_house.request", name);
+    assert_eq!(field("operation").as_deref(), Some("GetCompanyProfile"));
+    assert_eq!(field("path").as_deref(), Some("/company/{company_number}"));
+    assert_eq!(field("status").as_deref(), Some("200"));
+    assert!(field("duration_ms").is_some());
+}
+
+#[tokio::test]
+async fn stream_connection_span_records_timepoint_progress() {
+    let fields = SpanFields::default();
+    let _guard = tracing_subscriber::registry()
+        .with(fields.clone())
+        .set_default();
+
+    let server = MockStreamingServer::start(API_KEY).await.unwrap();
+    for timepoint in 40..=42 {
+        server.push_item("/filings", fixtures::filing(timepoint));
+    }
+
+    let client = CompaniesHouseStreamingClient::new(API_KEY).base_url(server.base_url());
+    let mut connection = client.stream(StreamFilings, Some(40)).await.unwrap();
+    for _ in 40..=42 {
+        connection.next().await.unwrap();
+    }
+
+    let field = |name| fields.get("companies_house.stream", name);
+    assert_eq!(field("endpoint").as_deref(), Some("/filings"));
+    assert_eq!(field("start_timepoint").as_deref(), Some("40"));
+    assert_eq!(field("status").as_deref(), Some("200"));
+    assert_eq!(field("timepoint").as_deref(), Some("42"));
+}
@@ -0,0 +1,148 @@
+use companies_house_api::{
+    operation::{
+        get_company_profile::{GetCompanyProfile, GetCompanyProfileStatusError},
+        search_companies::SearchCompanies,
+        CompaniesHousePublicDataOperation, CompaniesHousePublicDataOperationError,
+    },
+    test_util::fixtures,
+    transport::{
+        HttpRequest, HttpResponse, HttpTransport, HttpTransportError, HttpTransportFuture,
+        OperationRequest,
+    },
+    types::CompanyNumber,
+    CompaniesHousePublicDataClient,
+};
+use reqwest::{header::HeaderMap, Method, StatusCode};
+use std::sync::{Arc, Mutex};
+
+/// Answers every request with the same response, recording the requests.
+#[derive(Clone)]
+struct FakeTransport {
+    status: StatusCode,
+    body: &'static str,
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+impl FakeTransport {
+    fn new(status: StatusCode, body: &'static str) -> Self {
+        Self {
+            status,
+            body,
+            requests: Default::default(),
+        }
+    }
+}
+
+impl HttpTransport for FakeTransport {
+    fn execute(&self, request: HttpRequest) -> HttpTransportFuture<'_> {
+        self.requests.lock().unwrap().push(request);
+        Box::pin(async move {
+            Ok(HttpResponse {
+                status: self.status,
+                headers: HeaderMap::new(),
+                body: self.body.into(),
+            })
+        })
+    }
+}
+
+struct FailingTransport;
+
+impl HttpTransport for FailingTransport {
+    fn execute(&self, _request: HttpRequest) -> HttpTransportFuture<'_> {
+        Box::pin(async { Err(HttpTransportError::new("connection refused")) })
+    }
+}
+
+fn get_company_profile() -> GetCompanyProfile {
+    GetCompanyProfile::builder()
+        .company_number(fixtures::COMPANY_NUMBER.parse::<CompanyNumber>().unwrap())
+        .build()
+}
+
+#[test]
+fn operations_describe_requests_without_a_client() {
+    let request = SearchCompanies::builder()
+        .query("example trading")
+        .items_per_page(Some(10))
+        .build()
+        .build_request();
+
+    assert_eq!(request.method, Method::GET);
+    assert_eq!(request.path, ["search", "companies"]);
+    assert_eq!(
+        request.query,
+        [
+            ("q".to_owned(), "example trading".to_owned()),
+            ("items_per_page".to_owned(), "10".to_owned()),
+        ]
+    );
+
+    let url = request
+        .url(&"https://example.com/prefix/".parse().unwrap())
+        .unwrap();
+    assert_eq!(
+        url.as_str(),
+        "https://example.com/prefix/search/companies?q=example+trading&items_per_page=10"
+    );
+}
+
+#[test]
+fn operation_request_url_rejects_relative_segments() {
+    let request = OperationRequest {
+        method: Method::GET,
+        path: vec!["company".to_owned(), "..".to_owned()],
+        query: Vec::new(),
+        headers: HeaderMap::new(),
+    };
+
+    assert!(request
+        .url(&"https://example.com".parse().unwrap())
+        .is_err());
+}
+
+#[tokio::test]
+async fn client_sends_through_custom_transport() {
+    let transport = FakeTransport::new(StatusCode::OK, fixtures::COMPANY_PROFILE);
+    let client = CompaniesHousePublicDataClient::new("fake-key")
+        .unwrap()
+        .transport(transport.clone());
+
+    let profile = client.send(get_company_profile()).await.unwrap();
+    assert_eq!(profile.company_number, fixtures::COMPANY_NUMBER);
+
+    let requests = transport.requests.lock().unwrap();
+    let [request] = requests.as_slice() else {
+        panic!("expected one request, got {requests:?}");
+    };
+    assert_eq!(request.method, Method::GET);
+    assert_eq!(
+        request.url.as_str(),
+        format!(
+            "https://api.company-information.service.gov.uk/company/{}",
+            fixtures::COMPANY_NUMBER
+        )
+    );
+    assert_eq!(request.headers["authorization"], "fake-key");
+}
+
+#[tokio::test]
+async fn client_maps_transport_status_and_errors() {
+    let client = CompaniesHousePublicDataClient::new("fake-key")
+        .unwrap()
+        .transport(FakeTransport::new(StatusCode::NOT_FOUND, ""));
+    assert!(matches!(
+        client.send(get_company_profile()).await,
+        Err(CompaniesHousePublicDataOperationError::Status(
+            GetCompanyProfileStatusError::NotFound
+        ))
+    ));
+
+    let client = CompaniesHousePublicDataClient::new("fake-key")
+        .unwrap()
+        .transport(FailingTransport);
+    assert!(matches!(
+        client.send(get_company_profile()).await,
+        Err(CompaniesHousePublicDataOperationError::Transport(_))
+    ));
+}
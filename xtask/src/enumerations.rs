@@ -0,0 +1,42 @@
+//! Converts the api-enumerations YAML vendored in `enumerations/` to the JSON
+//! embedded by the crate, so the crate itself does not depend on a YAML parser.
+
+use serde_json::Value;
+use std::{fs, io, path::Path};
+
+/// Directory of vendored api-enumerations YAML, relative to the repository root.
+pub const ENUMERATIONS_PATH: &str = "enumerations";
+
+/// Directory of the embedded JSON, relative to the repository root.
+pub const EMBEDDED_PATH: &str = "src/enumerations";
+
+/// The vendored files, without their extension.
+pub const FILES: &[&str] = &["constants", "filing_history_descriptions"];
+
+/// Convert an api-enumerations YAML document to pretty printed JSON, with keys
+/// sorted so the output is stable.
+pub fn convert(yaml: &str) -> Result<String, serde_yaml_ng::Error> {
+    let value: Value = serde_yaml_ng::from_str(yaml)?;
+    let mut json = serde_json::to_string_pretty(&value).expect("JSON values serialize");
+    json.push('\n');
+    Ok(json)
+}
+
+/// Convert every file in [`FILES`] under `root`, returning each embedded path
+/// relative to `root` with its JSON.
+pub fn convert_all(root: &Path) -> io::Result<Vec<(String, String)>> {
+    FILES
+        .iter()
+        .map(|file| {
+            let yaml_path = root.join(ENUMERATIONS_PATH).join(format!("{file}.yml"));
+            let yaml = fs::read_to_string(&yaml_path)?;
+            let json = convert(&yaml).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {err}", yaml_path.display()),
+                )
+            })?;
+            Ok((format!("{EMBEDDED_PATH}/{file}.json"), json))
+        })
+        .collect()
+}
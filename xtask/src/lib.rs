@@ -0,0 +1,370 @@
+//! Generates public data types and operations from the Companies House
+//! swagger specs vendored in `specs/`.
+
+pub mod enumerations;
+
+use serde_json::{Map, Value};
+use std::{collections::BTreeMap, fmt::Write, fs, io, path::Path};
+
+/// Path of the generated module, relative to the repository root.
+pub const GENERATED_PATH: &str = "src/public_data/generated.rs";
+
+/// Directory of vendored swagger JSON specs, relative to the repository root.
+pub const SPECS_PATH: &str = "specs";
+
+const HEADER: &str = "\
+//! Types and operations generated from the swagger specs in `specs/`.
+//!
+//! Do not edit by hand, run `cargo xtask codegen` instead.
+";
+
+const KEYWORDS: &[&str] = &[
+    "as", "break", "const", "continue", "else", "enum", "extern", "false", "fn", "for", "if",
+    "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "type", "unsafe", "use", "where", "while", "async", "await", "dyn",
+];
+
+/// Read every `*.json` spec in `specs_dir`, in file name order.
+pub fn read_specs(specs_dir: &Path) -> io::Result<Vec<Value>> {
+    let mut paths = match fs::read_dir(specs_dir) {
+        Ok(entries) => entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(err) => return Err(err),
+    };
+    paths.retain(|path| {
+        path.extension()
+            .is_some_and(|extension| extension == "json")
+    });
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let spec = fs::read(path)?;
+            serde_json::from_slice(&spec).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {err}", path.display()),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Generate the module source for a set of swagger 2.0 specs.
+///
+/// Definitions with the same name in several specs are generated once.
+pub fn generate(specs: &[Value]) -> String {
+    let mut generator = Generator::default();
+    for spec in specs {
+        if let Some(definitions) = spec.get("definitions").and_then(Value::as_object) {
+            for (name, schema) in definitions {
+                generator.definition(&pascal_case(name), schema);
+            }
+        }
+        if let Some(paths) = spec.get("paths").and_then(Value::as_object) {
+            for (path, item) in paths {
+                if let Some(operation) = item.get("get") {
+                    generator.operation(path, item, operation);
+                }
+            }
+        }
+    }
+
+    let mut source = HEADER.to_owned();
+    if generator.items.is_empty() {
+        return source;
+    }
+    source.push_str(
+        "\n#![allow(clippy::all, unused_imports)]\n\n\
+         use serde::{Deserialize, Serialize};\n\n\
+         use crate::macros::{operation, string_enum};\n",
+    );
+    for item in generator.items.values() {
+        source.push('\n');
+        source.push_str(item);
+    }
+    source
+}
+
+#[derive(Default)]
+struct Generator {
+    /// Generated items keyed by name, so output order is stable.
+    items: BTreeMap<String, String>,
+}
+
+impl Generator {
+    fn definition(&mut self, name: &str, schema: &Value) {
+        if self.items.contains_key(name) {
+            return;
+        }
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            self.string_enum(name, schema, values);
+            return;
+        }
+
+        let properties = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .cloned()
+            .unwrap_or_default();
+        let required = string_array(schema.get("required"));
+
+        // Reserve the name first, as nested types are generated while writing fields.
+        self.items.insert(name.to_owned(), String::new());
+
+        let mut item = String::new();
+        write_doc(&mut item, schema, "");
+        item.push_str("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n");
+        item.push_str("#[cfg_attr(feature = \"strict\", serde(deny_unknown_fields))]\n");
+        writeln!(item, "pub struct {name} {{").unwrap();
+        for (property, property_schema) in &properties {
+            let ty = self.field_type(name, property, property_schema);
+            let ty = if required.contains(property) {
+                ty
+            } else {
+                format!("Option<{ty}>")
+            };
+            write_doc(&mut item, property_schema, "    ");
+            let field = field_ident(&snake_case(property));
+            if field.trim_start_matches("r#") != property {
+                writeln!(item, "    #[serde(rename = {property:?})]").unwrap();
+            }
+            writeln!(item, "    pub {field}: {ty},").unwrap();
+        }
+        item.push_str("}\n");
+
+        self.items.insert(name.to_owned(), item);
+    }
+
+    fn string_enum(&mut self, name: &str, schema: &Value, values: &[Value]) {
+        let mut item = String::from("string_enum! {\n");
+        let mut doc = String::new();
+        write_doc(&mut doc, schema, "    ");
+        item.push_str(&doc);
+        writeln!(item, "    pub enum {name} {{").unwrap();
+        for value in values.iter().filter_map(Value::as_str) {
+            writeln!(item, "        {} = {value:?},", pascal_case(value)).unwrap();
+        }
+        item.push_str("    }\n}\n");
+        self.items.insert(name.to_owned(), item);
+    }
+
+    fn field_type(&mut self, parent: &str, property: &str, schema: &Value) -> String {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return reference_type(reference);
+        }
+        match schema.get("type").and_then(Value::as_str) {
+            Some("string") if schema.get("enum").is_some() => {
+                let name = format!("{parent}{}", pascal_case(property));
+                self.definition(&name, schema);
+                name
+            }
+            Some("string") => match schema.get("format").and_then(Value::as_str) {
+                Some("date") => "crate::types::Date".to_owned(),
+                _ => "String".to_owned(),
+            },
+            Some("integer") if is_count(property) => "usize".to_owned(),
+            Some("integer") => "i64".to_owned(),
+            Some("number") => "f64".to_owned(),
+            Some("boolean") => "bool".to_owned(),
+            Some("array") => {
+                let items = schema.get("items").unwrap_or(&Value::Null);
+                format!("Vec<{}>", self.field_type(parent, property, items))
+            }
+            Some("object") if schema.get("properties").is_some() => {
+                let name = format!("{parent}{}", pascal_case(property));
+                self.definition(&name, schema);
+                name
+            }
+            _ => "serde_json::Value".to_owned(),
+        }
+    }
+
+    fn operation(&mut self, path: &str, item: &Value, operation: &Value) {
+        let Some(operation_id) = operation.get("operationId").and_then(Value::as_str) else {
+            return;
+        };
+        let name = pascal_case(operation_id);
+        if self.items.contains_key(&name) {
+            return;
+        }
+
+        let parameters: Vec<&Map<String, Value>> = [item, operation]
+            .into_iter()
+            .filter_map(|value| value.get("parameters").and_then(Value::as_array))
+            .flatten()
+            .filter_map(Value::as_object)
+            .collect();
+        let data = operation
+            .pointer("/responses/200/schema/$ref")
+            .and_then(Value::as_str)
+            .map(reference_type)
+            .unwrap_or_else(|| "serde_json::Value".to_owned());
+
+        let mut source = String::from("operation! {\n");
+        write_doc(&mut source, operation, "    ");
+        writeln!(source, "    pub struct {name} {{").unwrap();
+        let mut query = Vec::new();
+        for parameter in &parameters {
+            let Some(parameter_name) = parameter.get("name").and_then(Value::as_str) else {
+                continue;
+            };
+            let location = parameter.get("in").and_then(Value::as_str);
+            if !matches!(location, Some("path" | "query")) {
+                continue;
+            }
+            let field = field_ident(&snake_case(parameter_name));
+            let ty = match parameter.get("type").and_then(Value::as_str) {
+                _ if field == "company_number" => "crate::types::CompanyNumber",
+                Some("integer") => "usize",
+                Some("boolean") => "bool",
+                _ => "String",
+            };
+            let required = parameter.get("required").and_then(Value::as_bool) == Some(true);
+            write_doc(
+                &mut source,
+                &Value::Object((*parameter).clone()),
+                "        ",
+            );
+            if required {
+                if !matches!(ty, "usize" | "bool") {
+                    source.push_str("        #[builder(setter(into))]\n");
+                }
+                writeln!(source, "        {field}: {ty},").unwrap();
+            } else {
+                source.push_str("        #[builder(default)]\n");
+                writeln!(source, "        {field}: Option<{ty}>,").unwrap();
+            }
+            if location == Some("query") {
+                query.push((parameter_name.to_owned(), field));
+            }
+        }
+        source.push_str("    }\n\n");
+
+        let segments: Vec<String> = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| match segment.strip_prefix('{') {
+                Some(parameter) => field_ident(&snake_case(parameter.trim_end_matches('}'))),
+                None => format!("{segment:?}"),
+            })
+            .collect();
+        writeln!(source, "    GET [{}] => {data};", segments.join(", ")).unwrap();
+
+        if !query.is_empty() {
+            source.push_str("\n    query {\n");
+            for (parameter, field) in &query {
+                writeln!(source, "        {parameter:?} => {field},").unwrap();
+            }
+            source.push_str("    }\n");
+        }
+
+        writeln!(source, "\n    pub enum {name}StatusError {{").unwrap();
+        if operation.pointer("/responses/404").is_some() {
+            source.push_str("        NOT_FOUND => NotFound(\"Not found\"),\n");
+        }
+        source.push_str("    }\n}\n");
+
+        self.items.insert(name, source);
+    }
+}
+
+fn write_doc(out: &mut String, schema: &Value, indent: &str) {
+    let doc = ["title", "summary", "description"]
+        .into_iter()
+        .find_map(|key| schema.get(key).and_then(Value::as_str));
+    if let Some(doc) = doc {
+        for line in doc.trim().lines() {
+            writeln!(out, "{indent}/// {}", line.trim_end()).unwrap();
+        }
+    }
+}
+
+fn string_array(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(Value::as_array)
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_owned)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `#/definitions/companyProfile` and `other.json#/definitions/companyProfile`
+/// both name the type `CompanyProfile`.
+fn reference_type(reference: &str) -> String {
+    pascal_case(reference.rsplit('/').next().unwrap_or(reference))
+}
+
+fn words(value: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut previous_lowercase = false;
+    for c in value.chars() {
+        if !c.is_ascii_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            previous_lowercase = false;
+            continue;
+        }
+        if c.is_ascii_uppercase() && previous_lowercase {
+            words.push(std::mem::take(&mut word));
+        }
+        previous_lowercase = c.is_ascii_lowercase() || c.is_ascii_digit();
+        word.push(c.to_ascii_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn pascal_case(value: &str) -> String {
+    let name: String = words(value)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    match name.chars().next() {
+        Some(first) if first.is_ascii_digit() => format!("Value{name}"),
+        Some(_) => name,
+        None => "Empty".to_owned(),
+    }
+}
+
+fn snake_case(value: &str) -> String {
+    words(value).join("_")
+}
+
+/// Counts and positions within a list, which are never negative.
+fn is_count(property: &str) -> bool {
+    matches!(property, "items_per_page" | "total_results")
+        || property.ends_with("_count")
+        || property.ends_with("_index")
+}
+
+/// `self` links are named like the hand-written types, and other keywords which
+/// cannot be raw identifiers get a trailing underscore instead.
+fn field_ident(name: &str) -> String {
+    if name == "self" {
+        "self_link".to_owned()
+    } else if matches!(name, "super" | "crate") {
+        format!("{name}_")
+    } else if KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_owned()
+    }
+}
@@ -0,0 +1,53 @@
+use std::{fs, path::Path, process::ExitCode};
+
+use xtask::{
+    enumerations::{self, ENUMERATIONS_PATH},
+    generate, read_specs, GENERATED_PATH, SPECS_PATH,
+};
+
+const USAGE: &str = "usage: cargo xtask (codegen | enumerations) [--check]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (task, check) = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [task @ ("codegen" | "enumerations")] => (task, false),
+        [task @ ("codegen" | "enumerations"), "--check"] => (task, true),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask is in the repository root");
+    let outputs = match task {
+        "codegen" => match read_specs(&root.join(SPECS_PATH)) {
+            Ok(specs) => vec![(GENERATED_PATH.to_owned(), generate(&specs))],
+            Err(err) => {
+                eprintln!("Unable to read specs: {err}");
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => match enumerations::convert_all(root) {
+            Ok(outputs) => outputs,
+            Err(err) => {
+                eprintln!("Unable to read {ENUMERATIONS_PATH}: {err}");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    for (path, output) in outputs {
+        if check {
+            if fs::read_to_string(root.join(&path)).ok().as_deref() != Some(output.as_str()) {
+                eprintln!("{path} is stale, run `cargo xtask {task}`");
+                return ExitCode::FAILURE;
+            }
+        } else if let Err(err) = fs::write(root.join(&path), output) {
+            eprintln!("Unable to write {path}: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,40 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use xtask::{generate, read_specs, GENERATED_PATH, SPECS_PATH};
+
+fn repository_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+#[test]
+fn generated_code_is_up_to_date() {
+    let root = repository_root();
+    let specs = read_specs(&root.join(SPECS_PATH)).unwrap();
+    let checked_in = fs::read_to_string(root.join(GENERATED_PATH)).unwrap();
+
+    assert!(
+        checked_in == generate(&specs),
+        "{GENERATED_PATH} is stale, run `cargo xtask codegen`"
+    );
+}
+
+/// The generator's output for the synthetic `tests/fixtures/example.json` spec
+/// is compared in full with `tests/fixtures/example.rs`.
+#[test]
+fn generates_example() {
+    let specs = read_specs(&fixtures()).unwrap();
+    let expected = fs::read_to_string(fixtures().join("example.rs")).unwrap();
+    let generated = generate(&specs);
+
+    assert!(
+        expected == generated,
+        "tests/fixtures/example.rs does not match the generator output:\n{generated}"
+    );
+}
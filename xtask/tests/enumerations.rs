@@ -0,0 +1,26 @@
+use std::{fs, path::Path};
+
+use xtask::enumerations::{convert, convert_all};
+
+#[test]
+fn embedded_enumerations_are_up_to_date() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+    for (path, json) in convert_all(root).unwrap() {
+        let checked_in = fs::read_to_string(root.join(&path)).unwrap();
+        assert!(
+            checked_in == json,
+            "{path} is stale, run `cargo xtask enumerations`"
+        );
+    }
+}
+
+#[test]
+fn converts_sections_in_key_order() {
+    let json = convert("company_status:\n  'open': \"Open\"\n  'active': \"Active\"\n").unwrap();
+
+    assert_eq!(
+        json,
+        "{\n  \"company_status\": {\n    \"active\": \"Active\",\n    \"open\": \"Open\"\n  }\n}\n"
+    );
+}
@@ -0,0 +1,62 @@
+//! Types and operations generated from the swagger specs in `specs/`.
+//!
+//! Do not edit by hand, run `cargo xtask codegen` instead.
+
+#![allow(clippy::all, unused_imports)]
+
+use serde::{Deserialize, Serialize};
+
+use crate::macros::{operation, string_enum};
+
+operation! {
+    /// List the widgets of a company
+    pub struct ListWidgets {
+        #[builder(setter(into))]
+        company_number: crate::types::CompanyNumber,
+        #[builder(default)]
+        items_per_page: Option<usize>,
+        #[builder(default)]
+        register_view: Option<bool>,
+    }
+
+    GET ["company", company_number, "widgets"] => WidgetList;
+
+    query {
+        "items_per_page" => items_per_page,
+        "register_view" => register_view,
+    }
+
+    pub enum ListWidgetsStatusError {
+        NOT_FOUND => NotFound("Not found"),
+    }
+}
+
+/// A widget.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Widget {
+    pub created_on: Option<crate::types::Date>,
+    pub links: Option<WidgetLinks>,
+    pub r#type: WidgetType,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct WidgetLinks {
+    #[serde(rename = "self")]
+    pub self_link: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct WidgetList {
+    pub items: Vec<Widget>,
+    pub total_results: Option<usize>,
+}
+
+string_enum! {
+    pub enum WidgetType {
+        Small = "small",
+        ExtraLarge = "extra-large",
+    }
+}